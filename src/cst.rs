@@ -0,0 +1,740 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// A minimal concrete syntax tree for block-style YAML, and edits on top of it
+// that only rewrite the nodes that changed. Comments, blank lines, quoting, and
+// the layout of the other nodes are left as is.
+//
+// This only supports the subset of YAML used by Dependabot configuration
+// files: block mappings and sequences, scalars, and flow collections (which
+// are treated as opaque scalars). Anchors, aliases, tags, and complex keys are
+// rejected.
+
+use alloc::{
+    format,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::ops::Range;
+
+use crate::{
+    emit,
+    error::{Class, Error},
+    yaml::Segment,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    /// A scalar or a flow collection.
+    Scalar(Range<usize>),
+    Mapping(Mapping),
+    Sequence(Sequence),
+}
+
+impl Node {
+    /// See [`Mapping::locate`].
+    fn locate(&self, src: &str, path: &[Segment<'_>]) -> Option<(usize, usize)> {
+        match self {
+            Self::Scalar(span) if !path.is_empty() && src[span.clone()].starts_with(['[', '{']) => {
+                None
+            }
+            Self::Scalar(span) => Some((span.start, 0)),
+            Self::Mapping(mapping) => mapping.locate(src, path),
+            Self::Sequence(sequence) => sequence.locate(src, path),
+        }
+    }
+
+    fn start(&self) -> usize {
+        match self {
+            Self::Scalar(span) => span.start,
+            Self::Mapping(mapping) => mapping.entries.first().map_or(0, |entry| entry.start),
+            Self::Sequence(sequence) => sequence.items.first().map_or(0, |item| item.start),
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Self::Scalar(span) => span.end,
+            Self::Mapping(mapping) => mapping.entries.last().map_or(0, |entry| entry.end),
+            Self::Sequence(sequence) => sequence.items.last().map_or(0, |item| item.end),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Mapping {
+    /// The column of the keys.
+    indent: usize,
+    pub(crate) entries: Vec<Entry>,
+}
+
+impl Mapping {
+    pub(crate) fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+
+    /// Returns the block mapping at `path`.
+    pub(crate) fn mapping(&self, path: &[Segment<'_>]) -> Option<&Self> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        match self.node(path)? {
+            Node::Mapping(mapping) => Some(mapping),
+            _ => None,
+        }
+    }
+
+    /// Returns the span of the node at `path`.
+    ///
+    /// Returns `None` if `path` doesn't exist, leads to an empty value, or
+    /// leads into a flow collection.
+    pub(crate) fn span(&self, path: &[Segment<'_>]) -> Option<Range<usize>> {
+        if path.is_empty() {
+            let start = self.entries.first().map_or(0, |entry| entry.start);
+            let end = self.entries.last().map_or(0, |entry| entry.end);
+            return Some(start..end);
+        }
+        let node = self.node(path)?;
+        Some(node.start()..node.end())
+    }
+
+    /// Returns the node at `path`, which must not be empty.
+    fn node(&self, path: &[Segment<'_>]) -> Option<&Node> {
+        let (Segment::Key(key), rest) = path.split_first()? else { return None };
+        let mut node = self.get(key)?.value.as_ref()?;
+        for segment in rest {
+            node = match (node, segment) {
+                (Node::Mapping(mapping), Segment::Key(key)) => mapping.get(key)?.value.as_ref()?,
+                (Node::Sequence(sequence), &Segment::Index(index)) => {
+                    sequence.items.get(index)?.value.as_ref()?
+                }
+                _ => return None,
+            };
+        }
+        Some(node)
+    }
+
+    /// Returns the offset at which serde_yaml reports the node at `path` (or,
+    /// if it doesn't exist, its nearest existing ancestor) to start, and the
+    /// number of segments of `path` that exist.
+    ///
+    /// Returns `None` if `path` leads into a flow collection, whose contents
+    /// are not parsed.
+    pub(crate) fn locate(&self, src: &str, path: &[Segment<'_>]) -> Option<(usize, usize)> {
+        // libyaml reports a block mapping to start at its first key.
+        let start = self.entries.first().map_or(0, |entry| entry.start);
+        let Some((Segment::Key(key), rest)) = path.split_first() else { return Some((start, 0)) };
+        let Some(entry) = self.get(key) else { return Some((start, 0)) };
+        let (offset, len) = match &entry.value {
+            Some(value) => value.locate(src, rest)?,
+            // libyaml reports an empty value to start after the `:`.
+            None => (entry.colon, 0),
+        };
+        Some((offset, len + 1))
+    }
+}
+
+impl Sequence {
+    /// See [`Mapping::locate`].
+    fn locate(&self, src: &str, path: &[Segment<'_>]) -> Option<(usize, usize)> {
+        let start = self.items.first().map_or(0, |item| item.start);
+        let Some((&Segment::Index(index), rest)) = path.split_first() else {
+            return Some((start, 0));
+        };
+        let Some(item) = self.items.get(index) else { return Some((start, 0)) };
+        let (offset, len) = match &item.value {
+            Some(value) => value.locate(src, rest)?,
+            None => (item.start + 1, 0),
+        };
+        Some((offset, len + 1))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    pub(crate) key: String,
+    /// The start of the key.
+    start: usize,
+    /// The end of the `:` after the key.
+    colon: usize,
+    /// The end of the value (or the `:` if the value is empty).
+    end: usize,
+    pub(crate) value: Option<Node>,
+}
+
+impl Entry {
+    /// Returns the span from the end of the `:` to the end of the value.
+    pub(crate) fn value_span(&self) -> Range<usize> {
+        self.colon..self.end
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Sequence {
+    /// The column of the `-` indicators.
+    indent: usize,
+    pub(crate) items: Vec<Item>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Item {
+    /// The start of the `-` indicator.
+    start: usize,
+    /// The end of the value (or the `-` if the value is empty).
+    end: usize,
+    pub(crate) value: Option<Node>,
+}
+
+/// Parses `src` whose root node is a block mapping.
+pub(crate) fn parse(src: &str) -> Result<Mapping, Error> {
+    let p = Parser { src, lines: lines(src) };
+    let Some(first) = p.next_content(0) else { return Err(unsupported("empty document")) };
+    let indent = p.lines[first].indent;
+    let (node, next) = p.block(first, indent, None)?;
+    if let Some(next) = p.next_content(next) {
+        return Err(error(next, "unexpected content"));
+    }
+    match node {
+        Node::Mapping(mapping) => Ok(mapping),
+        _ => Err(unsupported("the root node is not a block mapping")),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    start: usize,
+    /// The end of the line (excluding the line break).
+    end: usize,
+    indent: usize,
+    /// Whether the line is blank, a comment, or a document marker.
+    trivia: bool,
+}
+
+fn lines(src: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in src.split_inclusive('\n') {
+        let end = start + line.trim_end_matches(['\n', '\r']).len();
+        let text = &src[start..end];
+        let content = text.trim_start_matches(' ');
+        let trivia = content.trim_end().is_empty()
+            || content.starts_with('#')
+            || start == 0 && (text == "---" || text.starts_with("--- #") || text.starts_with('%'));
+        lines.push(Line { start, end, indent: text.len() - content.len(), trivia });
+        start += line.len();
+    }
+    lines
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    lines: Vec<Line>,
+}
+
+impl Parser<'_> {
+    fn next_content(&self, from: usize) -> Option<usize> {
+        (from..self.lines.len()).find(|&i| !self.lines[i].trivia)
+    }
+
+    /// Returns the rest of the line from `offset`.
+    fn rest(&self, line: usize, offset: usize) -> &str {
+        &self.src[offset..self.lines[line].end]
+    }
+
+    /// Parses the node that starts at column `col` of `line`.
+    ///
+    /// `parent` is the column of the key or `-` indicator that owns the node.
+    /// Returns the node and the index of the line after it.
+    fn block(
+        &self,
+        line: usize,
+        col: usize,
+        parent: Option<usize>,
+    ) -> Result<(Node, usize), Error> {
+        let offset = self.lines[line].start + col;
+        let rest = self.rest(line, offset);
+        if is_indicator(rest, '-') {
+            self.sequence(line, col)
+        } else if key(rest).is_some() {
+            self.mapping(line, col)
+        } else {
+            let (node, end, next) = self.scalar(line, offset, parent)?;
+            let tail = self.src[end..line_end(self.src, end)].trim_start();
+            if !tail.is_empty() && !tail.starts_with('#') {
+                return Err(error(line, "unexpected content after value"));
+            }
+            Ok((node, next))
+        }
+    }
+
+    fn mapping(&self, mut line: usize, col: usize) -> Result<(Node, usize), Error> {
+        let mut entries = Vec::new();
+        loop {
+            let start = self.lines[line].start + col;
+            let Some((key, colon)) = key(self.rest(line, start)) else {
+                return Err(error(line, "expected a mapping key"));
+            };
+            let colon = start + colon;
+            let (value, end, next) = self.value(line, colon, col, true)?;
+            entries.push(Entry { key, start, colon, end, value });
+            match self.next_content(next) {
+                Some(i) if self.lines[i].indent == col => line = i,
+                Some(i) if self.lines[i].indent > col => {
+                    return Err(error(i, "unexpected indentation"));
+                }
+                _ => return Ok((Node::Mapping(Mapping { indent: col, entries }), next)),
+            }
+        }
+    }
+
+    fn sequence(&self, mut line: usize, col: usize) -> Result<(Node, usize), Error> {
+        let mut items = Vec::new();
+        loop {
+            let start = self.lines[line].start + col;
+            let (value, end, next) = self.value(line, start + 1, col, false)?;
+            items.push(Item { start, end, value });
+            match self.next_content(next) {
+                Some(i)
+                    if self.lines[i].indent == col
+                        && is_indicator(self.rest(i, self.lines[i].start + col), '-') =>
+                {
+                    line = i;
+                }
+                Some(i) if self.lines[i].indent > col => {
+                    return Err(error(i, "unexpected indentation"));
+                }
+                _ => return Ok((Node::Sequence(Sequence { indent: col, items }), next)),
+            }
+        }
+    }
+
+    /// Parses the value after the `:` of a mapping entry or the `-` of a
+    /// sequence item, which ends at `offset`.
+    ///
+    /// Returns the value, the end of the value, and the index of the line
+    /// after it.
+    fn value(
+        &self,
+        line: usize,
+        offset: usize,
+        indent: usize,
+        is_entry: bool,
+    ) -> Result<(Option<Node>, usize, usize), Error> {
+        let rest = self.rest(line, offset);
+        let content = rest.trim_start_matches(' ');
+        if content.is_empty() || content.starts_with('#') {
+            // The value (if any) is on the following lines.
+            return Ok(match self.next_content(line + 1) {
+                Some(i)
+                    if self.lines[i].indent > indent
+                        || is_entry
+                            && self.lines[i].indent == indent
+                            && is_indicator(self.rest(i, self.lines[i].start + indent), '-') =>
+                {
+                    let (node, next) = self.block(i, self.lines[i].indent, Some(indent))?;
+                    let end = node.end();
+                    (Some(node), end, next)
+                }
+                _ => (None, offset, line + 1),
+            });
+        }
+        let start = offset + rest.len() - content.len();
+        if is_entry {
+            let (node, end, next) = self.scalar(line, start, Some(indent))?;
+            return Ok((Some(node), end, next));
+        }
+        // A compact nested node such as `- key: value` or `- - value`.
+        let (node, next) = self.block(line, start - self.lines[line].start, Some(indent))?;
+        let end = node.end();
+        Ok((Some(node), end, next))
+    }
+
+    /// Parses a scalar or a flow collection that starts at `start`.
+    ///
+    /// Returns the node, the end of the node, and the index of the line after
+    /// it.
+    fn scalar(
+        &self,
+        line: usize,
+        start: usize,
+        parent: Option<usize>,
+    ) -> Result<(Node, usize, usize), Error> {
+        let parent = parent.unwrap_or(0);
+        let src = self.src;
+        let end = match src.as_bytes()[start] {
+            b'&' | b'*' | b'!' | b'?' => {
+                return Err(error(
+                    line,
+                    "anchors, aliases, tags, and complex keys are not supported",
+                ));
+            }
+            b'|' | b'>' => {
+                // A block scalar continues while lines are blank or more indented.
+                let mut end = self.lines[line].end;
+                for l in &self.lines[line + 1..] {
+                    let blank = src[l.start..l.end].trim().is_empty();
+                    if !blank && l.indent <= parent {
+                        break;
+                    }
+                    if !blank {
+                        end = l.end;
+                    }
+                }
+                end
+            }
+            b'"' | b'\'' => quoted(src, start).ok_or_else(|| error(line, "unterminated string"))?,
+            b'[' | b'{' => {
+                flow(src, start).ok_or_else(|| error(line, "unterminated flow collection"))?
+            }
+            _ => {
+                // A plain scalar continues on more indented lines.
+                let mut end = start + plain_len(&src[start..self.lines[line].end]);
+                for l in &self.lines[line + 1..] {
+                    let text = src[l.start..l.end].trim();
+                    if text.is_empty() || text.starts_with('#') || l.indent <= parent {
+                        break;
+                    }
+                    end = l.start + l.indent + plain_len(&src[l.start + l.indent..l.end]);
+                }
+                end
+            }
+        };
+        Ok((Node::Scalar(start..end), end, line_of(&self.lines, end) + 1))
+    }
+}
+
+/// Returns the index of the line containing `offset`.
+fn line_of(lines: &[Line], offset: usize) -> usize {
+    lines.partition_point(|l| l.end < offset).min(lines.len().saturating_sub(1))
+}
+
+/// Returns `true` if `s` starts with `indicator` followed by a space or the
+/// end of the line.
+fn is_indicator(s: &str, indicator: char) -> bool {
+    s.strip_prefix(indicator).is_some_and(|s| s.is_empty() || s.starts_with([' ', '\t']))
+}
+
+/// Parses the key of a mapping entry at the start of `s`, and returns the key
+/// and the offset of the end of the `:`.
+fn key(s: &str) -> Option<(String, usize)> {
+    let (key, rest) = match s.as_bytes().first()? {
+        b'"' | b'\'' => {
+            let end = quoted(s, 0)?;
+            (serde_yaml::from_str(&s[..end]).ok()?, end)
+        }
+        b'-' if is_indicator(s, '-') => return None,
+        b'#' | b'[' | b'{' | b'|' | b'>' | b'&' | b'*' | b'!' | b'?' => return None,
+        _ => {
+            let len = plain_len(s);
+            let colon = s[..len]
+                .match_indices(':')
+                .map(|(i, _)| i)
+                .find(|&i| is_indicator(&s[i..], ':'))?;
+            (s[..colon].trim_end().to_string(), colon)
+        }
+    };
+    let colon = rest + s[rest..].len() - s[rest..].trim_start_matches(' ').len();
+    is_indicator(&s[colon..], ':').then_some((key, colon + 1))
+}
+
+/// Returns the length of the plain scalar at the start of `s`, excluding the
+/// trailing comment and whitespace.
+fn plain_len(s: &str) -> usize {
+    let end = s.match_indices(" #").next().map_or(s.len(), |(i, _)| i);
+    s[..end].trim_end().len()
+}
+
+/// Returns the end of the quoted scalar that starts at `start`.
+fn quoted(s: &str, start: usize) -> Option<usize> {
+    let quote = s.as_bytes()[start];
+    let mut chars = s[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        if quote == b'"' && c == '\\' {
+            chars.next();
+        } else if c == char::from(quote) {
+            if quote == b'\'' && s[start + 1 + i + 1..].starts_with('\'') {
+                chars.next();
+                continue;
+            }
+            return Some(start + 1 + i + 1);
+        }
+    }
+    None
+}
+
+/// Returns the end of the flow collection that starts at `start`.
+fn flow(s: &str, start: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut i = start;
+    while i < s.len() {
+        match s.as_bytes()[i] {
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b'"' | b'\'' => {
+                i = quoted(s, i)?;
+                continue;
+            }
+            b'#' if s.as_bytes()[i - 1].is_ascii_whitespace() => {
+                i = s[i..].find('\n').map_or(s.len(), |n| i + n);
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn error(line: usize, msg: &str) -> Error {
+    unsupported(&format!("{msg} at line {}", line + 1))
+}
+
+fn unsupported(msg: &str) -> Error {
+    Error::parse(Class::Other, format!("unsupported YAML: {msg}"))
+}
+
+/// Returns the start of the line containing `offset`.
+fn line_start(src: &str, offset: usize) -> usize {
+    src[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Returns the end of the line containing `offset` (excluding the line break).
+fn line_end(src: &str, offset: usize) -> usize {
+    let end = src[offset..].find('\n').map_or(src.len(), |i| offset + i);
+    if src[..end].ends_with('\r') { end - 1 } else { end }
+}
+
+/// Returns the start of the line after the line containing `offset`.
+fn next_line(src: &str, offset: usize) -> usize {
+    src[offset..].find('\n').map_or(src.len(), |i| offset + i + 1)
+}
+
+/// The layout of the text inserted into a document, which follows the layout
+/// of the existing text.
+struct Style {
+    /// The line break of the first line.
+    newline: &'static str,
+    indent: emit::Indent,
+}
+
+impl Style {
+    /// Returns the style for inserting text into `mapping`.
+    fn for_mapping(src: &str, mapping: &Mapping) -> Self {
+        let mut indent = InferIndent::default();
+        indent.mapping(mapping);
+        Self::new(src, indent.finish())
+    }
+
+    /// Returns the style for inserting text into `sequence`.
+    fn for_sequence(src: &str, sequence: &Sequence) -> Self {
+        let mut indent = InferIndent::default();
+        indent.sequence(sequence);
+        Self::new(src, indent.finish())
+    }
+
+    fn new(src: &str, indent: emit::Indent) -> Self {
+        let newline =
+            if src.find('\n').is_some_and(|i| src[..i].ends_with('\r')) { "\r\n" } else { "\n" };
+        Self { newline, indent }
+    }
+}
+
+/// Infers the indentation of block collections in mappings from the first
+/// nested collection of each kind.
+#[derive(Default)]
+struct InferIndent {
+    mapping: Option<usize>,
+    sequence: Option<usize>,
+}
+
+impl InferIndent {
+    fn mapping(&mut self, mapping: &Mapping) {
+        for value in mapping.entries.iter().filter_map(|entry| entry.value.as_ref()) {
+            match value {
+                Node::Mapping(child) => {
+                    self.mapping.get_or_insert(child.indent.saturating_sub(mapping.indent));
+                }
+                Node::Sequence(child) => {
+                    self.sequence.get_or_insert(child.indent.saturating_sub(mapping.indent));
+                }
+                Node::Scalar(_) => {}
+            }
+            self.node(value);
+        }
+    }
+
+    fn sequence(&mut self, sequence: &Sequence) {
+        for value in sequence.items.iter().filter_map(|item| item.value.as_ref()) {
+            self.node(value);
+        }
+    }
+
+    fn finish(self) -> emit::Indent {
+        // Without nested collections, use the canonical layout.
+        let mapping = self.mapping.unwrap_or(2);
+        emit::Indent { mapping, sequence: self.sequence.unwrap_or(mapping) }
+    }
+
+    fn node(&mut self, node: &Node) {
+        if self.mapping.is_some() && self.sequence.is_some() {
+            return;
+        }
+        match node {
+            Node::Scalar(_) => {}
+            Node::Mapping(mapping) => self.mapping(mapping),
+            Node::Sequence(sequence) => self.sequence(sequence),
+        }
+    }
+}
+
+/// Renders `value` as the text that follows the `:` of a mapping entry whose
+/// key is at column `indent`.
+fn render(value: &serde_yaml::Value, indent: usize, style: &Style) -> Result<String, Error> {
+    let s = emit::to_string_with_indent(value, style.indent)?;
+    // The column of the lines of a block collection, which starts on the next line.
+    let block = match value {
+        serde_yaml::Value::Mapping(m) if !m.is_empty() => Some(indent + style.indent.mapping),
+        serde_yaml::Value::Sequence(s) if !s.is_empty() => Some(indent + style.indent.sequence),
+        _ => None,
+    };
+    let mut out = String::new();
+    for (i, line) in s.lines().enumerate() {
+        if i == 0 && block.is_none() {
+            out.push(' ');
+        } else {
+            out.push_str(style.newline);
+            if !line.is_empty() {
+                out.extend(core::iter::repeat_n(' ', block.unwrap_or(indent)));
+            }
+        }
+        out.push_str(line);
+    }
+    Ok(out)
+}
+
+/// Renders `value` as a sequence item whose `-` indicator is at column
+/// `indent` (excluding the indentation of the first line).
+fn render_item(value: &serde_yaml::Value, indent: usize, style: &Style) -> Result<String, Error> {
+    let s = emit::to_string_with_indent(value, style.indent)?;
+    let mut out = String::from("-");
+    for (i, line) in s.lines().enumerate() {
+        if i == 0 {
+            out.push(' ');
+        } else {
+            out.push_str(style.newline);
+            if !line.is_empty() {
+                out.extend(core::iter::repeat_n(' ', indent + 2));
+            }
+        }
+        out.push_str(line);
+    }
+    Ok(out)
+}
+
+/// Sets the value of `key` in `mapping` to `value`, or removes the entry if
+/// `value` is `None`.
+///
+/// A new entry is inserted before the first entry that comes after it in
+/// `order`.
+pub(crate) fn set(
+    src: &str,
+    mapping: &Mapping,
+    key: &str,
+    value: Option<&serde_yaml::Value>,
+    order: &[&str],
+) -> Result<String, Error> {
+    let mut out = String::from(src);
+    let style = Style::for_mapping(src, mapping);
+    let nl = style.newline;
+    let entries = &mapping.entries;
+    match (entries.iter().position(|entry| entry.key == key), value) {
+        (Some(i), Some(value)) => {
+            let entry = &entries[i];
+            out.replace_range(entry.colon..entry.end, &render(value, mapping.indent, &style)?);
+        }
+        (Some(i), None) => {
+            let entry = &entries[i];
+            let start = line_start(src, entry.start);
+            if start + mapping.indent == entry.start {
+                out.replace_range(remove_lines(src, start, entry.end, i == 0), "");
+            } else {
+                // The entry follows a `-` indicator; move the next entry to its place.
+                let end = entries.get(i + 1).map_or(line_end(src, entry.end), |next| next.start);
+                out.replace_range(entry.start..end, "");
+            }
+        }
+        (None, Some(value)) => {
+            let text = format!("{key}:{}", render(value, mapping.indent, &style)?);
+            let rank = |key: &str| order.iter().position(|k| *k == key).unwrap_or(order.len());
+            let next = entries.iter().position(|entry| rank(&entry.key) > rank(key));
+            let indent: String = core::iter::repeat_n(' ', mapping.indent).collect();
+            match next {
+                Some(0) => {
+                    let entry = &entries[0];
+                    let start = line_start(src, entry.start);
+                    if start + mapping.indent == entry.start {
+                        out.insert_str(start, &format!("{indent}{text}{nl}"));
+                    } else {
+                        out.insert_str(entry.start, &format!("{text}{nl}{indent}"));
+                    }
+                }
+                _ => {
+                    let prev = &entries[next.unwrap_or(entries.len()) - 1];
+                    out.insert_str(line_end(src, prev.end), &format!("{nl}{indent}{text}"));
+                }
+            }
+        }
+        (None, None) => {}
+    }
+    Ok(out)
+}
+
+/// Appends `value` to `sequence`.
+pub(crate) fn push(
+    src: &str,
+    sequence: &Sequence,
+    value: &serde_yaml::Value,
+) -> Result<String, Error> {
+    let mut out = String::from(src);
+    let style = Style::for_sequence(src, sequence);
+    let last = sequence.items.last().map_or(0, |item| item.end);
+    let indent: String = core::iter::repeat_n(' ', sequence.indent).collect();
+    out.insert_str(
+        line_end(src, last),
+        &format!("{}{indent}{}", style.newline, render_item(value, sequence.indent, &style)?),
+    );
+    Ok(out)
+}
+
+/// Removes the item at `index` from `sequence`.
+pub(crate) fn remove(src: &str, sequence: &Sequence, index: usize) -> String {
+    let mut out = String::from(src);
+    let item = &sequence.items[index];
+    out.replace_range(remove_lines(src, line_start(src, item.start), item.end, index == 0), "");
+    out
+}
+
+/// Returns the range to remove the lines from `start` (the start of a line)
+/// to the line containing `end`.
+///
+/// The blank lines that follow are also removed if they would otherwise
+/// follow the parent or another blank line.
+fn remove_lines(src: &str, start: usize, end: usize, first: bool) -> Range<usize> {
+    let mut end = next_line(src, end);
+    let after_blank =
+        start > 0 && src[..start - 1].rsplit('\n').next().is_some_and(|l| l.trim().is_empty());
+    if first || after_blank {
+        while let Some(line) = src[end..]
+            .split_inclusive('\n')
+            .next()
+            .filter(|l| l.ends_with('\n') && l.trim().is_empty())
+        {
+            end += line.len();
+        }
+    }
+    start..end
+}
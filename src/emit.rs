@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Serializes values as block-style YAML in the canonical layout: mappings and
+// sequences are indented by two spaces (including sequences in mappings), and
+// scalars are quoted only when needed.
+//
+// serde_yaml follows YAML 1.2, so strings such as `yes` and `10:30` are not
+// quoted by it, but YAML 1.1 parsers interpret them as a boolean and a
+// (sexagesimal) number. We quote them for compatibility with such parsers.
+
+use alloc::string::String;
+use core::fmt::Write as _;
+
+use serde::ser::Serialize;
+use serde_yaml::{Value, value::Tag};
+
+use crate::error::Error;
+
+/// The number of spaces by which block collections in a mapping are indented
+/// relative to their key.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Indent {
+    pub(crate) mapping: usize,
+    pub(crate) sequence: usize,
+}
+
+impl Indent {
+    const CANONICAL: Self = Self { mapping: 2, sequence: 2 };
+}
+
+/// Serializes `value` in the canonical layout.
+pub(crate) fn to_string<T: ?Sized + Serialize>(value: &T) -> Result<String, Error> {
+    to_string_with_indent(value, Indent::CANONICAL)
+}
+
+/// Serializes `value` in the canonical layout, except that block collections
+/// in mappings are indented by `step`.
+pub(crate) fn to_string_with_indent<T: ?Sized + Serialize>(
+    value: &T,
+    step: Indent,
+) -> Result<String, Error> {
+    let value = serde_yaml::to_value(value).map_err(Error::new)?;
+    let mut out = String::new();
+    node(&mut out, &value, 0, step, false)?;
+    out.push('\n');
+    Ok(out)
+}
+
+/// Writes `value` whose lines are indented by `indent` spaces.
+///
+/// If `inline` is `true`, the first line follows the text that has already
+/// been written (e.g., `- `) and is not indented.
+fn node(
+    out: &mut String,
+    value: &Value,
+    indent: usize,
+    step: Indent,
+    inline: bool,
+) -> Result<(), Error> {
+    match value {
+        Value::Mapping(mapping) if !mapping.is_empty() => {
+            for (i, (key, value)) in mapping.iter().enumerate() {
+                if i != 0 || !inline {
+                    newline(out, indent);
+                }
+                scalar(out, key, indent)?;
+                out.push(':');
+                if is_block(value) {
+                    let (tag, value) = untag(value);
+                    if let Some(tag) = tag {
+                        let _ = write!(out, " {tag}");
+                    }
+                    let offset = if value.is_sequence() { step.sequence } else { step.mapping };
+                    node(out, value, indent + offset, step, false)?;
+                } else {
+                    out.push(' ');
+                    scalar(out, value, indent)?;
+                }
+            }
+        }
+        Value::Sequence(sequence) if !sequence.is_empty() => {
+            for (i, value) in sequence.iter().enumerate() {
+                if i != 0 || !inline {
+                    newline(out, indent);
+                }
+                out.push_str("- ");
+                if is_block(value) {
+                    let (tag, value) = untag(value);
+                    if let Some(tag) = tag {
+                        // The collection starts on the next line.
+                        let _ = write!(out, "{tag}");
+                    }
+                    node(out, value, indent + 2, step, tag.is_none())?;
+                } else {
+                    scalar(out, value, indent)?;
+                }
+            }
+        }
+        _ => scalar(out, value, indent)?,
+    }
+    Ok(())
+}
+
+fn is_block(value: &Value) -> bool {
+    match value {
+        Value::Mapping(mapping) => !mapping.is_empty(),
+        Value::Sequence(sequence) => !sequence.is_empty(),
+        Value::Tagged(tagged) => is_block(&tagged.value),
+        _ => false,
+    }
+}
+
+/// Splits `value` into its tag (if any) and the tagged value.
+fn untag(value: &Value) -> (Option<&Tag>, &Value) {
+    match value {
+        Value::Tagged(tagged) => (Some(&tagged.tag), &tagged.value),
+        _ => (None, value),
+    }
+}
+
+/// Writes a scalar (or an empty collection) that is owned by a node at column
+/// `indent`.
+fn scalar(out: &mut String, value: &Value, indent: usize) -> Result<(), Error> {
+    if let Value::String(s) = value {
+        if is_yaml11_non_string(s) {
+            out.push('\'');
+            out.push_str(s);
+            out.push('\'');
+            return Ok(());
+        }
+    }
+    let s = serde_yaml::to_string(value).map_err(Error::new)?;
+    for (i, line) in s.trim_end_matches('\n').split('\n').enumerate() {
+        if i != 0 {
+            // A continuation line of a block scalar.
+            if line.is_empty() {
+                out.push('\n');
+            } else {
+                newline(out, indent);
+            }
+        }
+        out.push_str(line);
+    }
+    Ok(())
+}
+
+/// Returns `true` if `s` is not a string in YAML 1.1: a boolean, a number, a
+/// merge key (`<<`), or a value key (`=`).
+fn is_yaml11_non_string(s: &str) -> bool {
+    const BOOLS: &[&str] = &[
+        "y", "Y", "yes", "Yes", "YES", "n", "N", "no", "No", "NO", "true", "True", "TRUE", "false",
+        "False", "FALSE", "on", "On", "ON", "off", "Off", "OFF",
+    ];
+    BOOLS.contains(&s) || s == "<<" || s == "=" || is_yaml11_number(s)
+}
+
+/// Returns `true` if `s` is an integer or a floating-point number in YAML
+/// 1.1, which allows `_` between digits (e.g., `1_000`), binary numbers (e.g.,
+/// `0b1010`), and sexagesimal numbers (e.g., `190:20:30` and `1:30.5`).
+fn is_yaml11_number(s: &str) -> bool {
+    let is_digits =
+        |s: &str, radix| !s.is_empty() && s.chars().all(|c| c.is_digit(radix) || c == '_');
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    if let Some(s) = s.strip_prefix("0b") {
+        return is_digits(s, 2);
+    }
+    if let Some(s) = s.strip_prefix("0x") {
+        return is_digits(s, 16);
+    }
+    let (int, frac) = s.split_once('.').map_or((s, None), |(int, frac)| (int, Some(frac)));
+    let mut parts = int.split(':');
+    let first = parts.next().unwrap_or_default();
+    let int = if int.is_empty() {
+        // e.g., `.5`
+        frac.is_some_and(|frac| frac.starts_with(|c: char| c.is_ascii_digit()))
+    } else {
+        first.starts_with(|c: char| c.is_ascii_digit())
+            && is_digits(first, 10)
+            && parts.all(|part| (1..=2).contains(&part.len()) && is_digits(part, 10))
+    };
+    let Some(frac) = frac else { return int };
+    let (frac, exp) = frac.split_once(['e', 'E']).map_or((frac, None), |(f, e)| (f, Some(e)));
+    int && (frac.is_empty() || is_digits(frac, 10))
+        && exp.is_none_or(|exp| exp.strip_prefix(['-', '+']).is_some_and(|exp| is_digits(exp, 10)))
+}
+
+fn newline(out: &mut String, indent: usize) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.extend(core::iter::repeat_n(' ', indent));
+}
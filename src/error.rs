@@ -1,7 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use alloc::boxed::Box;
-use core::{fmt, marker::PhantomData};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString as _},
+};
+use core::{
+    fmt::{self, Write as _},
+    marker::PhantomData,
+};
 use std::io;
 
 /// An error that occurred during parsing the Dependabot configuration.
@@ -15,20 +22,260 @@ pub struct Error(ErrorKind, PhantomData<Box<dyn Send + Sync>>);
 // don't expose dependencies' types directly in the public API.
 #[derive(Debug)]
 pub(crate) enum ErrorKind {
-    /// An error that occurred during parsing the configuration.
+    /// An error from serde_yaml that this crate has not classified, e.g., a
+    /// syntax error or an error during serialization.
     Yaml(serde_yaml::Error),
+    /// An error that occurred during parsing the configuration or a value
+    /// from a string.
+    // Boxed to keep `Error` (and every `Result` containing it) small.
+    Parse(Box<ParseError>),
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseError {
+    class: Class,
+    msg: Box<str>,
+    location: Option<Location>,
+    source: Option<serde_yaml::Error>,
 }
 
 impl Error {
     pub(crate) fn new(e: impl Into<ErrorKind>) -> Self {
         Self(e.into(), PhantomData)
     }
+
+    /// Creates an error of the given class that is not located in the input.
+    pub(crate) fn parse(class: Class, msg: impl Into<Box<str>>) -> Self {
+        Self::new(ErrorKind::Parse(Box::new(ParseError {
+            class,
+            msg: msg.into(),
+            location: None,
+            source: None,
+        })))
+    }
+
+    /// Creates an error about the value of `property` in the format of
+    /// Dependabot's errors, e.g., `The property 'version' value "1" did not
+    /// match: 2`.
+    ///
+    /// The class is determined by `property`, so [`Class::of_yaml`] recovers
+    /// it from the message if the error is passed through serde, e.g., with
+    /// `de::Error::custom`.
+    pub(crate) fn property(property: &str, value: impl fmt::Display, reason: &str) -> Self {
+        Self::parse(
+            Class::of_property(property),
+            format!("The property '{property}' value \"{value}\" {reason}"),
+        )
+    }
+
+    /// Creates an error of the given class from serde_yaml's error about the
+    /// value at `path`.
+    pub(crate) fn yaml(e: serde_yaml::Error, class: Class, path: Option<String>) -> Self {
+        let location = e.location().map(|location| Location {
+            line: location.line(),
+            column: location.column(),
+            index: location.index(),
+            path,
+        });
+        Self::new(ErrorKind::Parse(Box::new(ParseError {
+            class,
+            msg: e.to_string().into(),
+            location,
+            source: Some(e),
+        })))
+    }
+
+    /// Creates an error of the given class at `location` in the input.
+    pub(crate) fn located(class: Class, msg: &str, location: Location) -> Self {
+        // Formatted in the same way as serde_yaml's errors.
+        let mut display = match &location.path {
+            Some(path) => format!("{path}: {msg}"),
+            None => msg.to_string(),
+        };
+        if location.line != 1 || location.column != 1 {
+            let _ = write!(display, " at line {} column {}", location.line, location.column);
+        }
+        Self::new(ErrorKind::Parse(Box::new(ParseError {
+            class,
+            msg: display.into(),
+            location: Some(location),
+            source: None,
+        })))
+    }
+
+    /// Returns the location in the input where the error occurred, if known.
+    #[must_use]
+    pub fn location(&self) -> Option<Location> {
+        match &self.0 {
+            ErrorKind::Yaml(e) => {
+                let location = e.location()?;
+                Some(Location {
+                    line: location.line(),
+                    column: location.column(),
+                    index: location.index(),
+                    path: None,
+                })
+            }
+            ErrorKind::Parse(e) => e.location.clone(),
+        }
+    }
+
+    /// Returns `true` if the input is not valid YAML.
+    #[must_use]
+    pub fn is_syntax(&self) -> bool {
+        self.class() == Class::Syntax
+    }
+
+    /// Returns `true` if the input contains a property that is not allowed.
+    #[must_use]
+    pub fn is_unknown_field(&self) -> bool {
+        self.class() == Class::UnknownField
+    }
+
+    /// Returns `true` if a value is not one of the allowed values, e.g., an
+    /// unsupported package ecosystem.
+    #[must_use]
+    pub fn is_invalid_enum_value(&self) -> bool {
+        self.class() == Class::InvalidEnumValue
+    }
+
+    /// Returns `true` if a required property is missing.
+    #[must_use]
+    pub fn is_missing_field(&self) -> bool {
+        self.class() == Class::MissingField
+    }
+
+    /// Returns `true` if the `version` property does not match the version of
+    /// the configuration being parsed.
+    #[must_use]
+    pub fn is_version_mismatch(&self) -> bool {
+        self.class() == Class::VersionMismatch
+    }
+
+    /// Returns `true` if the `pull-request-branch-name.separator` property is
+    /// not one of the allowed separators.
+    #[must_use]
+    pub fn is_invalid_separator(&self) -> bool {
+        self.class() == Class::InvalidSeparator
+    }
+
+    /// Returns `true` if a value has the expected type but violates a
+    /// constraint, e.g., a malformed cron expression or a registry missing a
+    /// property required for its type.
+    #[must_use]
+    pub fn is_semantic(&self) -> bool {
+        self.class() == Class::Semantic
+    }
+
+    fn class(&self) -> Class {
+        match &self.0 {
+            // Errors from the YAML parser are the only unclassified errors
+            // that have a location.
+            ErrorKind::Yaml(e) if e.location().is_some() => Class::Syntax,
+            ErrorKind::Yaml(_) => Class::Other,
+            ErrorKind::Parse(e) => e.class,
+        }
+    }
+}
+
+/// The class of an error, determined where the error is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Class {
+    Syntax,
+    UnknownField,
+    InvalidEnumValue,
+    MissingField,
+    VersionMismatch,
+    InvalidSeparator,
+    Semantic,
+    Other,
+}
+
+impl Class {
+    /// Classifies serde_yaml's error about a value of the input, given its
+    /// message without the path and location.
+    ///
+    /// serde's errors only carry a message, so errors are classified by the
+    /// format of their messages: those of [`Error::property`] and
+    /// `TryFrom<Registry>`, and those of the default implementations of
+    /// `serde::de::Error` methods, which derived `Deserialize` impls use.
+    pub(crate) fn of_yaml(msg: &str) -> Self {
+        if let Some((property, _)) =
+            msg.strip_prefix("The property '").and_then(|rest| rest.split_once('\''))
+        {
+            return Self::of_property(property);
+        }
+        if msg.starts_with("registry of type '") {
+            Self::Semantic
+        } else if msg.starts_with("unknown field `") {
+            Self::UnknownField
+        } else if msg.starts_with("unknown variant `") {
+            Self::InvalidEnumValue
+        } else if msg.starts_with("missing field `") {
+            Self::MissingField
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns the class of an error about the value of `property`.
+    fn of_property(property: &str) -> Self {
+        match property {
+            "version" => Self::VersionMismatch,
+            "pull-request-branch-name/separator" => Self::InvalidSeparator,
+            _ => Self::Semantic,
+        }
+    }
+}
+
+/// The location in the input where an error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Location {
+    line: usize,
+    column: usize,
+    index: usize,
+    path: Option<String>,
+}
+
+impl Location {
+    pub(crate) fn new(line: usize, column: usize, index: usize, path: Option<String>) -> Self {
+        Self { line, column, index, path }
+    }
+
+    /// Returns the line number (1-based).
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number (1-based).
+    #[must_use]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the byte offset from the start of the input (0-based).
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the path to the offending value, e.g., `updates[2].schedule.interval`.
+    ///
+    /// Returns `None` if the error is not about a specific value, e.g., a
+    /// syntax error, or the error is about the top-level value.
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.0 {
             ErrorKind::Yaml(e) => fmt::Display::fmt(e, f),
+            ErrorKind::Parse(e) => f.write_str(&e.msg),
         }
     }
 }
@@ -37,6 +284,7 @@ impl core::error::Error for Error {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self.0 {
             ErrorKind::Yaml(e) => Some(e),
+            ErrorKind::Parse(e) => e.source.as_ref().map(|e| e as &dyn core::error::Error),
         }
     }
 }
@@ -45,6 +293,10 @@ impl From<Error> for io::Error {
     fn from(e: Error) -> Self {
         match e.0 {
             ErrorKind::Yaml(e) => Self::new(io::ErrorKind::InvalidData, e),
+            ErrorKind::Parse(e) => match e.source {
+                Some(e) => Self::new(io::ErrorKind::InvalidData, e),
+                None => Self::new(io::ErrorKind::InvalidData, String::from(e.msg)),
+            },
         }
     }
 }
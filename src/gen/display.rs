@@ -88,6 +88,50 @@ impl fmt::Display for crate::v1::VersionRequirementUpdate {
         }
     }
 }
+impl fmt::Display for crate::v2::ParseMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Strict => f.write_str("strict"),
+            Self::Lenient => f.write_str("lenient"),
+        }
+    }
+}
+impl fmt::Display for crate::v2::DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingDirectory => f.write_str("conflicting-directory"),
+            Self::MissingDirectory => f.write_str("missing-directory"),
+            Self::EmptyDirectories => f.write_str("empty-directories"),
+            Self::DuplicateUpdate => f.write_str("duplicate-update"),
+            Self::MissingCronjob => f.write_str("missing-cronjob"),
+            Self::UnexpectedCronjob => f.write_str("unexpected-cronjob"),
+            Self::UnexpectedTime => f.write_str("unexpected-time"),
+            Self::UndefinedRegistry => f.write_str("undefined-registry"),
+        }
+    }
+}
+impl fmt::Display for crate::v2::LossyItemCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LiveSchedule => f.write_str("live-schedule"),
+            Self::AutomergedUpdates => f.write_str("automerged-updates"),
+            Self::GoDep => f.write_str("go-dep"),
+            Self::SecurityOnlyUpdates => f.write_str("security-only-updates"),
+        }
+    }
+}
+impl fmt::Display for crate::v2::DecisionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Update => f.write_str("update"),
+            Self::NotNewer => f.write_str("not-newer"),
+            Self::NotAllowed => f.write_str("not-allowed"),
+            Self::Ignored => f.write_str("ignored"),
+            Self::LockfileOnly => f.write_str("lockfile-only"),
+            Self::PullRequestLimit => f.write_str("pull-request-limit"),
+        }
+    }
+}
 impl fmt::Display for crate::v2::PackageEcosystem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -114,6 +158,8 @@ impl fmt::Display for crate::v2::PackageEcosystem {
             Self::Swift => f.write_str("swift"),
             Self::Terraform => f.write_str("terraform"),
             Self::Uv => f.write_str("uv"),
+            #[cfg(feature = "other-variants")]
+            Self::Other(s) => fmt::Display::fmt(s, f),
         }
     }
 }
@@ -123,6 +169,10 @@ impl fmt::Display for crate::v2::Interval {
             Self::Daily => f.write_str("daily"),
             Self::Weekly => f.write_str("weekly"),
             Self::Monthly => f.write_str("monthly"),
+            Self::Quarterly => f.write_str("quarterly"),
+            Self::Semiannually => f.write_str("semiannually"),
+            Self::Yearly => f.write_str("yearly"),
+            Self::Cron => f.write_str("cron"),
         }
     }
 }
@@ -147,6 +197,8 @@ impl fmt::Display for crate::v2::DependencyType {
             Self::All => f.write_str("all"),
             Self::Production => f.write_str("production"),
             Self::Development => f.write_str("development"),
+            #[cfg(feature = "other-variants")]
+            Self::Other(s) => fmt::Display::fmt(s, f),
         }
     }
 }
@@ -157,6 +209,31 @@ impl fmt::Display for crate::v2::CommitMessageInclude {
         }
     }
 }
+impl fmt::Display for crate::v2::GroupAppliesTo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionUpdates => f.write_str("version-updates"),
+            Self::SecurityUpdates => f.write_str("security-updates"),
+        }
+    }
+}
+impl fmt::Display for crate::v2::GroupDependencyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Development => f.write_str("development"),
+            Self::Production => f.write_str("production"),
+        }
+    }
+}
+impl fmt::Display for crate::v2::GroupUpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Major => f.write_str("major"),
+            Self::Minor => f.write_str("minor"),
+            Self::Patch => f.write_str("patch"),
+        }
+    }
+}
 impl fmt::Display for crate::v2::UpdateType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -190,22 +267,31 @@ impl fmt::Display for crate::v2::VersioningStrategy {
             Self::IncreaseIfNecessary => f.write_str("increase-if-necessary"),
             Self::LockfileOnly => f.write_str("lockfile-only"),
             Self::Widen => f.write_str("widen"),
+            #[cfg(feature = "other-variants")]
+            Self::Other(s) => fmt::Display::fmt(s, f),
         }
     }
 }
 impl fmt::Display for crate::v2::RegistryType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::CargoRegistry => f.write_str("cargo-registry"),
             Self::ComposerRepository => f.write_str("composer-repository"),
             Self::DockerRegistry => f.write_str("docker-registry"),
             Self::Git => f.write_str("git"),
+            Self::GoproxyServer => f.write_str("goproxy-server"),
+            Self::HelmRegistry => f.write_str("helm-registry"),
             Self::HexOrganization => f.write_str("hex-organization"),
+            Self::HexRepository => f.write_str("hex-repository"),
             Self::MavenRepository => f.write_str("maven-repository"),
             Self::NpmRegistry => f.write_str("npm-registry"),
             Self::NugetFeed => f.write_str("nuget-feed"),
+            Self::PubRepository => f.write_str("pub-repository"),
             Self::PythonIndex => f.write_str("python-index"),
             Self::RubygemsServer => f.write_str("rubygems-server"),
             Self::TerraformRegistry => f.write_str("terraform-registry"),
+            #[cfg(feature = "other-variants")]
+            Self::Other(s) => fmt::Display::fmt(s, f),
         }
     }
 }
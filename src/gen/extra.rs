@@ -0,0 +1,459 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by dependabot-config-internal-codegen
+// (gen_extra function at tools/codegen/src/main.rs).
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+use alloc::{string::String, vec::Vec};
+use indexmap::IndexMap;
+use serde::de::{Deserialize, Deserializer};
+use serde_derive::Deserialize;
+#[allow(clippy::wildcard_imports)]
+use crate::{v2::*, Value};
+impl Extra for crate::v2::Dependabot {
+    const FIELDS: &'static [&'static str] = &[
+        "version",
+        "enable-beta-ecosystems",
+        "registries",
+        "updates",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DependabotFields {
+    #[serde(deserialize_with = "de_version")]
+    version: u8,
+    enable_beta_ecosystems: Option<bool>,
+    #[serde(default)]
+    registries: Registries,
+    updates: Vec<Update>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Dependabot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (DependabotFields, _) = deserialize_extra(
+            deserializer,
+            "Dependabot",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            version: fields.version,
+            enable_beta_ecosystems: fields.enable_beta_ecosystems,
+            registries: fields.registries,
+            updates: fields.updates,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Update {
+    const FIELDS: &'static [&'static str] = &[
+        "package-ecosystem",
+        "directory",
+        "directories",
+        "schedule",
+        "allow",
+        "assignees",
+        "commit-message",
+        "cooldown",
+        "exclude-paths",
+        "groups",
+        "ignore",
+        "insecure-external-code-execution",
+        "labels",
+        "milestone",
+        "open-pull-requests-limit",
+        "pull-request-branch-name",
+        "rebase-strategy",
+        "registries",
+        "reviewers",
+        "target-branch",
+        "vendor",
+        "versioning-strategy",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct UpdateFields {
+    package_ecosystem: PackageEcosystem,
+    directory: Option<String>,
+    directories: Option<Vec<PathPattern>>,
+    schedule: Schedule,
+    allow: Option<Vec<Allow>>,
+    assignees: Option<Vec<String>>,
+    commit_message: Option<CommitMessage>,
+    cooldown: Option<Cooldown>,
+    exclude_paths: Option<Vec<PathPattern>>,
+    groups: Option<IndexMap<String, Group>>,
+    ignore: Option<Vec<Ignore>>,
+    insecure_external_code_execution: Option<InsecureExternalCodeExecution>,
+    labels: Option<Vec<String>>,
+    milestone: Option<u32>,
+    open_pull_requests_limit: Option<u32>,
+    pull_request_branch_name: Option<PullRequestBranchName>,
+    rebase_strategy: Option<RebaseStrategy>,
+    registries: Option<UpdateRegistries>,
+    reviewers: Option<Vec<String>>,
+    target_branch: Option<String>,
+    vendor: Option<bool>,
+    versioning_strategy: Option<VersioningStrategy>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Update {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (UpdateFields, _) = deserialize_extra(
+            deserializer,
+            "Update",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            package_ecosystem: fields.package_ecosystem,
+            directory: fields.directory,
+            directories: fields.directories,
+            schedule: fields.schedule,
+            allow: fields.allow,
+            assignees: fields.assignees,
+            commit_message: fields.commit_message,
+            cooldown: fields.cooldown,
+            exclude_paths: fields.exclude_paths,
+            groups: fields.groups,
+            ignore: fields.ignore,
+            insecure_external_code_execution: fields.insecure_external_code_execution,
+            labels: fields.labels,
+            milestone: fields.milestone,
+            open_pull_requests_limit: fields.open_pull_requests_limit,
+            pull_request_branch_name: fields.pull_request_branch_name,
+            rebase_strategy: fields.rebase_strategy,
+            registries: fields.registries,
+            reviewers: fields.reviewers,
+            target_branch: fields.target_branch,
+            vendor: fields.vendor,
+            versioning_strategy: fields.versioning_strategy,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Schedule {
+    const FIELDS: &'static [&'static str] = &[
+        "interval",
+        "day",
+        "time",
+        "timezone",
+        "cronjob",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ScheduleFields {
+    interval: Interval,
+    day: Option<Day>,
+    time: Option<ScheduleTime>,
+    timezone: Option<Timezone>,
+    cronjob: Option<CronExpression>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Schedule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (ScheduleFields, _) = deserialize_extra(
+            deserializer,
+            "Schedule",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            interval: fields.interval,
+            day: fields.day,
+            time: fields.time,
+            timezone: fields.timezone,
+            cronjob: fields.cronjob,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Allow {
+    const FIELDS: &'static [&'static str] = &["dependency-name", "dependency-type"];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct AllowFields {
+    dependency_name: Option<String>,
+    dependency_type: Option<DependencyType>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Allow {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (AllowFields, _) = deserialize_extra(
+            deserializer,
+            "Allow",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            dependency_name: fields.dependency_name,
+            dependency_type: fields.dependency_type,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::CommitMessage {
+    const FIELDS: &'static [&'static str] = &["prefix", "prefix-development", "include"];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CommitMessageFields {
+    prefix: Option<String>,
+    prefix_development: Option<String>,
+    include: Option<CommitMessageInclude>,
+}
+impl<'de> Deserialize<'de> for crate::v2::CommitMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (CommitMessageFields, _) = deserialize_extra(
+            deserializer,
+            "CommitMessage",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            prefix: fields.prefix,
+            prefix_development: fields.prefix_development,
+            include: fields.include,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Cooldown {
+    const FIELDS: &'static [&'static str] = &[
+        "default-days",
+        "semver-major-days",
+        "semver-minor-days",
+        "semver-patch-days",
+        "include",
+        "exclude",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CooldownFields {
+    #[serde(default, deserialize_with = "de_default_days")]
+    default_days: Option<u32>,
+    #[serde(default, deserialize_with = "de_semver_major_days")]
+    semver_major_days: Option<u32>,
+    #[serde(default, deserialize_with = "de_semver_minor_days")]
+    semver_minor_days: Option<u32>,
+    #[serde(default, deserialize_with = "de_semver_patch_days")]
+    semver_patch_days: Option<u32>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Cooldown {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (CooldownFields, _) = deserialize_extra(
+            deserializer,
+            "Cooldown",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            default_days: fields.default_days,
+            semver_major_days: fields.semver_major_days,
+            semver_minor_days: fields.semver_minor_days,
+            semver_patch_days: fields.semver_patch_days,
+            include: fields.include,
+            exclude: fields.exclude,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Group {
+    const FIELDS: &'static [&'static str] = &[
+        "applies-to",
+        "dependency-type",
+        "patterns",
+        "exclude-patterns",
+        "update-types",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct GroupFields {
+    applies_to: Option<GroupAppliesTo>,
+    dependency_type: Option<GroupDependencyType>,
+    patterns: Option<Vec<String>>,
+    exclude_patterns: Option<Vec<String>>,
+    update_types: Option<Vec<GroupUpdateType>>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Group {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (GroupFields, _) = deserialize_extra(
+            deserializer,
+            "Group",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            applies_to: fields.applies_to,
+            dependency_type: fields.dependency_type,
+            patterns: fields.patterns,
+            exclude_patterns: fields.exclude_patterns,
+            update_types: fields.update_types,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Ignore {
+    const FIELDS: &'static [&'static str] = &[
+        "dependency-name",
+        "versions",
+        "update-types",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct IgnoreFields {
+    dependency_name: String,
+    versions: Option<Vec<String>>,
+    update_types: Option<Vec<UpdateType>>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Ignore {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (IgnoreFields, _) = deserialize_extra(
+            deserializer,
+            "Ignore",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            dependency_name: fields.dependency_name,
+            versions: fields.versions,
+            update_types: fields.update_types,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::PullRequestBranchName {
+    const FIELDS: &'static [&'static str] = &["separator"];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PullRequestBranchNameFields {
+    separator: Separator,
+}
+impl<'de> Deserialize<'de> for crate::v2::PullRequestBranchName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (PullRequestBranchNameFields, _) = deserialize_extra(
+            deserializer,
+            "PullRequestBranchName",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            separator: fields.separator,
+            extra,
+        })
+    }
+}
+impl Extra for crate::v2::Registry {
+    const FIELDS: &'static [&'static str] = &[
+        "type",
+        "url",
+        "username",
+        "password",
+        "key",
+        "token",
+        "replaces-base",
+        "organization",
+        "repo",
+        "auth-key",
+        "public-key-fingerprint",
+        "registry",
+    ];
+    fn extra(&self) -> &IndexMap<String, Value> {
+        &self.extra
+    }
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct RegistryFields {
+    #[serde(rename = "type")]
+    type_: RegistryType,
+    url: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    key: Option<String>,
+    token: Option<String>,
+    replaces_base: Option<bool>,
+    organization: Option<String>,
+    repo: Option<String>,
+    auth_key: Option<String>,
+    public_key_fingerprint: Option<String>,
+    registry: Option<String>,
+}
+impl<'de> Deserialize<'de> for crate::v2::Registry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (fields, extra): (RegistryFields, _) = deserialize_extra(
+            deserializer,
+            "Registry",
+            Self::FIELDS,
+        )?;
+        Ok(Self {
+            type_: fields.type_,
+            url: fields.url,
+            username: fields.username,
+            password: fields.password,
+            key: fields.key,
+            token: fields.token,
+            replaces_base: fields.replaces_base,
+            organization: fields.organization,
+            repo: fields.repo,
+            auth_key: fields.auth_key,
+            public_key_fingerprint: fields.public_key_fingerprint,
+            registry: fields.registry,
+            extra,
+        })
+    }
+}
@@ -9,216 +9,294 @@ use crate::Error;
 impl FromStr for crate::Dependabot {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::Dependabot {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::UpdateConfig {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::PackageManager {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::UpdateSchedule {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AllowedUpdate {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AllowedUpdateMatch {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AllowedDependencyType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AllowedUpdateType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::IgnoredUpdate {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::IgnoredUpdateMatch {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AutomergedUpdate {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AutomergedUpdateMatch {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AutomergedDependencyType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::AutomergedUpdateType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::VersionRequirementUpdate {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v1::CommitMessage {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Dependabot {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::ParseMode {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::DiagnosticCode {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::LossyItemCode {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Update {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::PackageEcosystem {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::PathPattern {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::NamePattern {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Schedule {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Interval {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Day {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Allow {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::DependencyType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::CommitMessage {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::CommitMessageInclude {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::Cooldown {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::Group {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::GroupAppliesTo {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::GroupDependencyType {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::GroupUpdateType {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Ignore {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::UpdateType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::InsecureExternalCodeExecution {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::PullRequestBranchName {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Separator {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::RebaseStrategy {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::UpdateRegistries {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::VersioningStrategy {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::Registry {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
     }
 }
 impl FromStr for crate::v2::RegistryType {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_yaml::from_str(s).map_err(Error::new)
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::TypedRegistry {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
+    }
+}
+impl FromStr for crate::v2::RegistryCredentials {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::yaml::from_str(s)
     }
 }
@@ -46,6 +46,11 @@ const _: fn() = || {
     assert_unpin::<crate::error::Error>();
     assert_not_unwind_safe!(crate::error::Error);
     assert_not_ref_unwind_safe!(crate::error::Error);
+    assert_send::<crate::error::Location>();
+    assert_sync::<crate::error::Location>();
+    assert_unpin::<crate::error::Location>();
+    assert_unwind_safe::<crate::error::Location>();
+    assert_ref_unwind_safe::<crate::error::Location>();
     assert_send::<crate::Dependabot>();
     assert_sync::<crate::Dependabot>();
     assert_unpin::<crate::Dependabot>();
@@ -136,16 +141,81 @@ const _: fn() = || {
     assert_unpin::<crate::v2::Dependabot>();
     assert_unwind_safe::<crate::v2::Dependabot>();
     assert_ref_unwind_safe::<crate::v2::Dependabot>();
+    assert_send::<crate::v2::ParseMode>();
+    assert_sync::<crate::v2::ParseMode>();
+    assert_unpin::<crate::v2::ParseMode>();
+    assert_unwind_safe::<crate::v2::ParseMode>();
+    assert_ref_unwind_safe::<crate::v2::ParseMode>();
+    assert_send::<crate::v2::Diagnostic>();
+    assert_sync::<crate::v2::Diagnostic>();
+    assert_unpin::<crate::v2::Diagnostic>();
+    assert_unwind_safe::<crate::v2::Diagnostic>();
+    assert_ref_unwind_safe::<crate::v2::Diagnostic>();
+    assert_send::<crate::v2::DiagnosticCode>();
+    assert_sync::<crate::v2::DiagnosticCode>();
+    assert_unpin::<crate::v2::DiagnosticCode>();
+    assert_unwind_safe::<crate::v2::DiagnosticCode>();
+    assert_ref_unwind_safe::<crate::v2::DiagnosticCode>();
+    assert_send::<crate::v2::LossyItem>();
+    assert_sync::<crate::v2::LossyItem>();
+    assert_unpin::<crate::v2::LossyItem>();
+    assert_unwind_safe::<crate::v2::LossyItem>();
+    assert_ref_unwind_safe::<crate::v2::LossyItem>();
+    assert_send::<crate::v2::LossyConversionError>();
+    assert_sync::<crate::v2::LossyConversionError>();
+    assert_unpin::<crate::v2::LossyConversionError>();
+    assert_unwind_safe::<crate::v2::LossyConversionError>();
+    assert_ref_unwind_safe::<crate::v2::LossyConversionError>();
+    assert_send::<crate::v2::LossyItemCode>();
+    assert_sync::<crate::v2::LossyItemCode>();
+    assert_unpin::<crate::v2::LossyItemCode>();
+    assert_unwind_safe::<crate::v2::LossyItemCode>();
+    assert_ref_unwind_safe::<crate::v2::LossyItemCode>();
+    assert_send::<crate::v2::Document>();
+    assert_sync::<crate::v2::Document>();
+    assert_unpin::<crate::v2::Document>();
+    assert_unwind_safe::<crate::v2::Document>();
+    assert_ref_unwind_safe::<crate::v2::Document>();
+    assert_send::<crate::v2::UpdateMut<'_>>();
+    assert_sync::<crate::v2::UpdateMut<'_>>();
+    assert_unpin::<crate::v2::UpdateMut<'_>>();
+    assert_not_unwind_safe!(crate::v2::UpdateMut<'_>);
+    assert_ref_unwind_safe::<crate::v2::UpdateMut<'_>>();
     assert_send::<crate::v2::Update>();
     assert_sync::<crate::v2::Update>();
     assert_unpin::<crate::v2::Update>();
     assert_unwind_safe::<crate::v2::Update>();
     assert_ref_unwind_safe::<crate::v2::Update>();
+    assert_send::<crate::v2::Candidate>();
+    assert_sync::<crate::v2::Candidate>();
+    assert_unpin::<crate::v2::Candidate>();
+    assert_unwind_safe::<crate::v2::Candidate>();
+    assert_ref_unwind_safe::<crate::v2::Candidate>();
+    assert_send::<crate::v2::Decision>();
+    assert_sync::<crate::v2::Decision>();
+    assert_unpin::<crate::v2::Decision>();
+    assert_unwind_safe::<crate::v2::Decision>();
+    assert_ref_unwind_safe::<crate::v2::Decision>();
+    assert_send::<crate::v2::DecisionReason>();
+    assert_sync::<crate::v2::DecisionReason>();
+    assert_unpin::<crate::v2::DecisionReason>();
+    assert_unwind_safe::<crate::v2::DecisionReason>();
+    assert_ref_unwind_safe::<crate::v2::DecisionReason>();
     assert_send::<crate::v2::PackageEcosystem>();
     assert_sync::<crate::v2::PackageEcosystem>();
     assert_unpin::<crate::v2::PackageEcosystem>();
     assert_unwind_safe::<crate::v2::PackageEcosystem>();
     assert_ref_unwind_safe::<crate::v2::PackageEcosystem>();
+    assert_send::<crate::v2::PathPattern>();
+    assert_sync::<crate::v2::PathPattern>();
+    assert_unpin::<crate::v2::PathPattern>();
+    assert_unwind_safe::<crate::v2::PathPattern>();
+    assert_ref_unwind_safe::<crate::v2::PathPattern>();
+    assert_send::<crate::v2::NamePattern>();
+    assert_sync::<crate::v2::NamePattern>();
+    assert_unpin::<crate::v2::NamePattern>();
+    assert_unwind_safe::<crate::v2::NamePattern>();
+    assert_ref_unwind_safe::<crate::v2::NamePattern>();
     assert_send::<crate::v2::Schedule>();
     assert_sync::<crate::v2::Schedule>();
     assert_unpin::<crate::v2::Schedule>();
@@ -156,6 +226,21 @@ const _: fn() = || {
     assert_unpin::<crate::v2::Interval>();
     assert_unwind_safe::<crate::v2::Interval>();
     assert_ref_unwind_safe::<crate::v2::Interval>();
+    assert_send::<crate::v2::ScheduleTime>();
+    assert_sync::<crate::v2::ScheduleTime>();
+    assert_unpin::<crate::v2::ScheduleTime>();
+    assert_unwind_safe::<crate::v2::ScheduleTime>();
+    assert_ref_unwind_safe::<crate::v2::ScheduleTime>();
+    assert_send::<crate::v2::Timezone>();
+    assert_sync::<crate::v2::Timezone>();
+    assert_unpin::<crate::v2::Timezone>();
+    assert_unwind_safe::<crate::v2::Timezone>();
+    assert_ref_unwind_safe::<crate::v2::Timezone>();
+    assert_send::<crate::v2::CronExpression>();
+    assert_sync::<crate::v2::CronExpression>();
+    assert_unpin::<crate::v2::CronExpression>();
+    assert_unwind_safe::<crate::v2::CronExpression>();
+    assert_ref_unwind_safe::<crate::v2::CronExpression>();
     assert_send::<crate::v2::Day>();
     assert_sync::<crate::v2::Day>();
     assert_unpin::<crate::v2::Day>();
@@ -181,11 +266,46 @@ const _: fn() = || {
     assert_unpin::<crate::v2::CommitMessageInclude>();
     assert_unwind_safe::<crate::v2::CommitMessageInclude>();
     assert_ref_unwind_safe::<crate::v2::CommitMessageInclude>();
+    assert_send::<crate::v2::Cooldown>();
+    assert_sync::<crate::v2::Cooldown>();
+    assert_unpin::<crate::v2::Cooldown>();
+    assert_unwind_safe::<crate::v2::Cooldown>();
+    assert_ref_unwind_safe::<crate::v2::Cooldown>();
+    assert_send::<crate::v2::Group>();
+    assert_sync::<crate::v2::Group>();
+    assert_unpin::<crate::v2::Group>();
+    assert_unwind_safe::<crate::v2::Group>();
+    assert_ref_unwind_safe::<crate::v2::Group>();
+    assert_send::<crate::v2::GroupAppliesTo>();
+    assert_sync::<crate::v2::GroupAppliesTo>();
+    assert_unpin::<crate::v2::GroupAppliesTo>();
+    assert_unwind_safe::<crate::v2::GroupAppliesTo>();
+    assert_ref_unwind_safe::<crate::v2::GroupAppliesTo>();
+    assert_send::<crate::v2::GroupDependencyType>();
+    assert_sync::<crate::v2::GroupDependencyType>();
+    assert_unpin::<crate::v2::GroupDependencyType>();
+    assert_unwind_safe::<crate::v2::GroupDependencyType>();
+    assert_ref_unwind_safe::<crate::v2::GroupDependencyType>();
+    assert_send::<crate::v2::GroupUpdateType>();
+    assert_sync::<crate::v2::GroupUpdateType>();
+    assert_unpin::<crate::v2::GroupUpdateType>();
+    assert_unwind_safe::<crate::v2::GroupUpdateType>();
+    assert_ref_unwind_safe::<crate::v2::GroupUpdateType>();
     assert_send::<crate::v2::Ignore>();
     assert_sync::<crate::v2::Ignore>();
     assert_unpin::<crate::v2::Ignore>();
     assert_unwind_safe::<crate::v2::Ignore>();
     assert_ref_unwind_safe::<crate::v2::Ignore>();
+    assert_send::<crate::v2::Version>();
+    assert_sync::<crate::v2::Version>();
+    assert_unpin::<crate::v2::Version>();
+    assert_unwind_safe::<crate::v2::Version>();
+    assert_ref_unwind_safe::<crate::v2::Version>();
+    assert_send::<crate::v2::VersionRequirement>();
+    assert_sync::<crate::v2::VersionRequirement>();
+    assert_unpin::<crate::v2::VersionRequirement>();
+    assert_unwind_safe::<crate::v2::VersionRequirement>();
+    assert_ref_unwind_safe::<crate::v2::VersionRequirement>();
     assert_send::<crate::v2::UpdateType>();
     assert_sync::<crate::v2::UpdateType>();
     assert_unpin::<crate::v2::UpdateType>();
@@ -211,6 +331,11 @@ const _: fn() = || {
     assert_unpin::<crate::v2::RebaseStrategy>();
     assert_unwind_safe::<crate::v2::RebaseStrategy>();
     assert_ref_unwind_safe::<crate::v2::RebaseStrategy>();
+    assert_send::<crate::v2::UpdateRegistries>();
+    assert_sync::<crate::v2::UpdateRegistries>();
+    assert_unpin::<crate::v2::UpdateRegistries>();
+    assert_unwind_safe::<crate::v2::UpdateRegistries>();
+    assert_ref_unwind_safe::<crate::v2::UpdateRegistries>();
     assert_send::<crate::v2::VersioningStrategy>();
     assert_sync::<crate::v2::VersioningStrategy>();
     assert_unpin::<crate::v2::VersioningStrategy>();
@@ -231,4 +356,19 @@ const _: fn() = || {
     assert_unpin::<crate::v2::RegistryType>();
     assert_unwind_safe::<crate::v2::RegistryType>();
     assert_ref_unwind_safe::<crate::v2::RegistryType>();
+    assert_send::<crate::v2::TypedRegistry>();
+    assert_sync::<crate::v2::TypedRegistry>();
+    assert_unpin::<crate::v2::TypedRegistry>();
+    assert_unwind_safe::<crate::v2::TypedRegistry>();
+    assert_ref_unwind_safe::<crate::v2::TypedRegistry>();
+    assert_send::<crate::v2::RegistryCredentials>();
+    assert_sync::<crate::v2::RegistryCredentials>();
+    assert_unpin::<crate::v2::RegistryCredentials>();
+    assert_unwind_safe::<crate::v2::RegistryCredentials>();
+    assert_ref_unwind_safe::<crate::v2::RegistryCredentials>();
+    assert_send::<crate::value::Value>();
+    assert_sync::<crate::value::Value>();
+    assert_unpin::<crate::value::Value>();
+    assert_unwind_safe::<crate::value::Value>();
+    assert_ref_unwind_safe::<crate::value::Value>();
 };
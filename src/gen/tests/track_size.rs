@@ -26,6 +26,7 @@ fn write_size<T>(out: &mut String) {
 fn track_size() {
     let mut out = String::new();
     write_size::<crate::error::Error>(&mut out);
+    write_size::<crate::error::Location>(&mut out);
     write_size::<crate::Dependabot>(&mut out);
     write_size::<crate::v1::Dependabot>(&mut out);
     write_size::<crate::v1::UpdateConfig>(&mut out);
@@ -44,24 +45,51 @@ fn track_size() {
     write_size::<crate::v1::VersionRequirementUpdate>(&mut out);
     write_size::<crate::v1::CommitMessage>(&mut out);
     write_size::<crate::v2::Dependabot>(&mut out);
+    write_size::<crate::v2::ParseMode>(&mut out);
+    write_size::<crate::v2::Diagnostic>(&mut out);
+    write_size::<crate::v2::DiagnosticCode>(&mut out);
+    write_size::<crate::v2::LossyItem>(&mut out);
+    write_size::<crate::v2::LossyConversionError>(&mut out);
+    write_size::<crate::v2::LossyItemCode>(&mut out);
+    write_size::<crate::v2::Document>(&mut out);
+    write_size::<crate::v2::UpdateMut<'_>>(&mut out);
     write_size::<crate::v2::Update>(&mut out);
+    write_size::<crate::v2::Candidate>(&mut out);
+    write_size::<crate::v2::Decision>(&mut out);
+    write_size::<crate::v2::DecisionReason>(&mut out);
     write_size::<crate::v2::PackageEcosystem>(&mut out);
+    write_size::<crate::v2::PathPattern>(&mut out);
+    write_size::<crate::v2::NamePattern>(&mut out);
     write_size::<crate::v2::Schedule>(&mut out);
     write_size::<crate::v2::Interval>(&mut out);
+    write_size::<crate::v2::ScheduleTime>(&mut out);
+    write_size::<crate::v2::Timezone>(&mut out);
+    write_size::<crate::v2::CronExpression>(&mut out);
     write_size::<crate::v2::Day>(&mut out);
     write_size::<crate::v2::Allow>(&mut out);
     write_size::<crate::v2::DependencyType>(&mut out);
     write_size::<crate::v2::CommitMessage>(&mut out);
     write_size::<crate::v2::CommitMessageInclude>(&mut out);
+    write_size::<crate::v2::Cooldown>(&mut out);
+    write_size::<crate::v2::Group>(&mut out);
+    write_size::<crate::v2::GroupAppliesTo>(&mut out);
+    write_size::<crate::v2::GroupDependencyType>(&mut out);
+    write_size::<crate::v2::GroupUpdateType>(&mut out);
     write_size::<crate::v2::Ignore>(&mut out);
+    write_size::<crate::v2::Version>(&mut out);
+    write_size::<crate::v2::VersionRequirement>(&mut out);
     write_size::<crate::v2::UpdateType>(&mut out);
     write_size::<crate::v2::InsecureExternalCodeExecution>(&mut out);
     write_size::<crate::v2::PullRequestBranchName>(&mut out);
     write_size::<crate::v2::Separator>(&mut out);
     write_size::<crate::v2::RebaseStrategy>(&mut out);
+    write_size::<crate::v2::UpdateRegistries>(&mut out);
     write_size::<crate::v2::VersioningStrategy>(&mut out);
     write_size::<crate::v2::Registry>(&mut out);
     write_size::<crate::v2::RegistryType>(&mut out);
+    write_size::<crate::v2::TypedRegistry>(&mut out);
+    write_size::<crate::v2::RegistryCredentials>(&mut out);
+    write_size::<crate::value::Value>(&mut out);
     test_helper::git::assert_diff(
         Path::new(env!("CARGO_MANIFEST_DIR")).join("src/gen/tests/track_size.txt"),
         out,
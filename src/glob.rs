@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Minimal glob matching shared by path and dependency name patterns.
+
+use alloc::vec::Vec;
+
+/// Returns `true` if `s` matches `pattern`, where `*` matches zero or more
+/// characters and `?` matches exactly one character.
+pub(crate) fn matches(pattern: &str, s: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let s: Vec<char> = s.chars().collect();
+    let (mut p, mut i) = (0, 0);
+    // Position of the last `*` in the pattern and the position in `s` where
+    // the characters matched by it end.
+    let mut backtrack = None;
+    while i < s.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, i));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == s[i] => {
+                p += 1;
+                i += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_i)) => {
+                    p = star_p + 1;
+                    i = star_i + 1;
+                    backtrack = Some((star_p, i));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns `true` if `path` matches `pattern`, both given as a list of path
+/// segments. In addition to the wildcards supported by [`matches`], a `**`
+/// segment matches zero or more segments.
+pub(crate) fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| matches_segments(rest, &path[i..])),
+        Some((first, rest)) => match path.split_first() {
+            Some((segment, path)) => matches(first, segment) && matches_segments(rest, path),
+            None => false,
+        },
+    }
+}
+
+/// Returns `true` if `s` matches the pattern consisting of `parts` separated
+/// by `*`, where `*` matches zero or more characters.
+pub(crate) fn matches_parts<S: AsRef<str>>(parts: &[S], s: &str) -> bool {
+    match parts {
+        [] => s.is_empty(),
+        [literal] => s == literal.as_ref(),
+        [first, middle @ .., last] => {
+            let Some(s) = s.strip_prefix(first.as_ref()) else { return false };
+            let Some(mut s) = s.strip_suffix(last.as_ref()) else { return false };
+            // Matching each part at its leftmost position leaves the most room
+            // for the remaining parts.
+            for part in middle {
+                let part = part.as_ref();
+                let Some(i) = s.find(part) else { return false };
+                s = &s[i + part.len()..];
+            }
+            true
+        }
+    }
+}
+
+/// Returns `true` if `s` contains any glob metacharacters.
+pub(crate) fn is_glob(s: &str) -> bool {
+    s.contains(['*', '?'])
+}
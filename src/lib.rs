@@ -30,6 +30,15 @@ for update in dependabot.updates {
 }
 ```
 
+## Optional features
+
+- **`other-variants`**<br>
+  Deserialize unknown package ecosystems, registry types, dependency types, and
+  versioning strategies into the `Other` variant of the corresponding enum
+  instead of returning an error. The `Other` variant is serialized back
+  verbatim. This is useful for tools that need to handle configuration files
+  using values added to Dependabot after the release of this crate.
+
 [dependabot]: https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/about-dependabot-version-updates
 
 <!-- tidy:sync-markdown-to-rustdoc:end -->
@@ -67,13 +76,26 @@ extern crate std;
 mod assert_impl;
 #[path = "gen/display.rs"]
 mod display;
+#[path = "gen/extra.rs"]
+mod extra;
 #[path = "gen/from_str.rs"]
 mod from_str;
 #[cfg(test)]
 #[path = "gen/tests/track_size.rs"]
 mod track_size;
+#[path = "gen/tz.rs"]
+mod tz;
 
+mod cst;
+mod emit;
 mod error;
+mod glob;
+mod md5;
+mod suggest;
+mod time;
+mod value;
+mod version;
+mod yaml;
 
 pub mod v1;
 pub mod v2;
@@ -82,7 +104,10 @@ use alloc::string::{String, ToString};
 
 use serde_derive::{Deserialize, Serialize};
 
-pub use self::error::Error;
+pub use self::{
+    error::{Error, Location},
+    value::Value,
+};
 
 /// The Dependabot configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// "Did you mean" suggestions for unknown keys and enum values.
+
+use alloc::{format, string::String, vec::Vec};
+
+use serde_yaml::Value;
+
+/// Properties of the version 1 configuration, and their version 2 equivalents.
+const V1_KEYS: &[(&str, Option<&str>)] = &[
+    ("allowed_updates", Some("allow")),
+    ("automerged_updates", None),
+    ("commit_message", Some("commit-message")),
+    ("default_assignees", Some("assignees")),
+    ("default_labels", Some("labels")),
+    ("default_milestone", Some("milestone")),
+    ("default_reviewers", Some("reviewers")),
+    ("dependency_name", Some("dependency-name")),
+    ("dependency_type", Some("dependency-type")),
+    ("ignored_updates", Some("ignore")),
+    ("package_manager", Some("package-ecosystem")),
+    ("target_branch", Some("target-branch")),
+    ("update_configs", Some("updates")),
+    ("update_schedule", Some("schedule")),
+    ("version_requirement", Some("versions")),
+    ("version_requirement_updates", Some("versioning-strategy")),
+];
+
+/// Returns `msg` with a suggestion appended, or `None` if there is nothing
+/// to suggest.
+///
+/// `msg` is the message of serde's error (without the path and location),
+/// and `node` is the value the error is about.
+pub(crate) fn suggest(msg: &str, node: Option<&Value>) -> Option<String> {
+    let suggestion = if let Some(rest) = msg.strip_prefix("unknown variant `") {
+        let (value, expected) = rest.split_once("`, expected ")?;
+        format!("did you mean `{}`?", closest(value, quoted(expected))?)
+    } else if let Some(rest) = msg.strip_prefix("unknown field `") {
+        let (field, expected) = rest.split_once('`')?;
+        let candidates = quoted(expected);
+        match V1_KEYS.iter().find(|&&(v1, _)| v1 == field) {
+            Some((_, Some(v2))) if candidates.contains(v2) => {
+                format!("`{field}` is a version 1 property; use `{v2}` in version 2")
+            }
+            Some((_, None)) => {
+                format!("`{field}` is a version 1 property that is not supported in version 2")
+            }
+            _ => format!("did you mean `{}`?", closest(field, candidates)?),
+        }
+    } else if let Some(rest) = msg.strip_prefix("missing field `") {
+        let (field, _) = rest.split_once('`')?;
+        let keys: Vec<&str> = node?.as_mapping()?.keys().filter_map(Value::as_str).collect();
+        if let Some(&(v1, _)) =
+            V1_KEYS.iter().find(|&&(v1, v2)| v2 == Some(field) && keys.contains(&v1))
+        {
+            format!("found version 1 property `{v1}`; use `{field}` in version 2")
+        } else {
+            let key = keys
+                .into_iter()
+                .filter(|key| is_similar(field, key))
+                .min_by_key(|key| distance(field, key))?;
+            format!("found `{key}`; did you mean `{field}`?")
+        }
+    } else {
+        return None;
+    };
+    Some(format!("{msg} ({suggestion})"))
+}
+
+/// Returns the strings enclosed in backticks.
+fn quoted(s: &str) -> Vec<&str> {
+    s.split('`').skip(1).step_by(2).collect()
+}
+
+/// Returns the candidate most similar to `s`, if any is similar enough.
+fn closest<'a>(s: &str, candidates: Vec<&'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .filter(|candidate| is_similar(s, candidate))
+        .min_by_key(|candidate| distance(s, candidate))
+}
+
+fn is_similar(a: &str, b: &str) -> bool {
+    a != b && distance(a, b) <= (a.chars().count().max(b.chars().count()) / 3).max(1)
+}
+
+/// Returns the Levenshtein distance between `a` and `b`, ignoring ASCII case
+/// and treating `_` as `-`.
+fn distance(a: &str, b: &str) -> usize {
+    let normalize = |c: char| if c == '_' { '-' } else { c.to_ascii_lowercase() };
+    let b: Vec<char> = b.chars().map(normalize).collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = Vec::with_capacity(b.len() + 1);
+    for (i, ca) in a.chars().map(normalize).enumerate() {
+        cur.clear();
+        cur.push(i + 1);
+        for (j, &cb) in b.iter().enumerate() {
+            cur.push((prev[j] + usize::from(ca != cb)).min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        core::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Minimal calendar and time zone arithmetic used to compute schedule run times.
+//
+// Times are represented as seconds since the Unix epoch (UTC), and local times as
+// seconds since the Unix epoch as if the local wall clock time were UTC.
+
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(crate) const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+pub(crate) fn to_unix(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => i64::try_from(d.as_secs()).unwrap_or(i64::MAX),
+        // Round toward negative infinity.
+        Err(e) => {
+            let d = e.duration();
+            let secs = i64::try_from(d.as_secs()).unwrap_or(i64::MAX);
+            if d.subsec_nanos() == 0 { -secs } else { -secs - 1 }
+        }
+    }
+}
+
+pub(crate) fn from_unix(secs: i64) -> Option<SystemTime> {
+    let d = Duration::from_secs(secs.unsigned_abs());
+    if secs >= 0 { UNIX_EPOCH.checked_add(d) } else { UNIX_EPOCH.checked_sub(d) }
+}
+
+/// Returns the number of days since 1970-01-01 of the given date.
+// Algorithm from https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub(crate) fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the (year, month, day) of the given number of days since 1970-01-01.
+// Algorithm from https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Returns the day of week (0-6, 0 is Sunday) of the given number of days since 1970-01-01.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn weekday(days: i64) -> u8 {
+    // 1970-01-01 was a Thursday.
+    (days + 4).rem_euclid(7) as u8
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A time zone rule, parsed from a POSIX TZ string such as
+/// `EST5EDT,M3.2.0,M11.1.0`.
+///
+/// See <https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html#tag_08_03>.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TzRule {
+    /// The offset of standard time from UTC, in seconds east of UTC.
+    std_offset: i64,
+    dst: Option<Dst>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dst {
+    /// The offset of daylight saving time from UTC, in seconds east of UTC.
+    offset: i64,
+    start: (TransitionDate, i64),
+    end: (TransitionDate, i64),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TransitionDate {
+    /// `Jn`: the Julian day n (1-365), not counting February 29.
+    Julian1(u16),
+    /// `n`: the zero-based Julian day n (0-365), counting February 29.
+    Julian0(u16),
+    /// `Mm.w.d`: the day d (0-6, 0 is Sunday) of week w (1-5, 5 is last) of month m (1-12).
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl TransitionDate {
+    /// Returns the number of days since 1970-01-01 of this date in the given year.
+    fn days(self, year: i64) -> i64 {
+        let jan1 = days_from_civil(year, 1, 1);
+        match self {
+            Self::Julian1(n) => {
+                let n = i64::from(n) - 1;
+                jan1 + if is_leap_year(year) && n >= 59 { n + 1 } else { n }
+            }
+            Self::Julian0(n) => jan1 + i64::from(n),
+            Self::MonthWeekDay(month, week, day) => {
+                let first = days_from_civil(year, month, 1);
+                let mut d = (day + 7 - weekday(first)) % 7 + (week - 1) * 7;
+                while d >= days_in_month(year, month) {
+                    d -= 7;
+                }
+                first + i64::from(d)
+            }
+        }
+    }
+}
+
+impl TzRule {
+    pub(crate) const UTC: Self = Self { std_offset: 0, dst: None };
+
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let mut p = Parser(s.as_bytes());
+        p.name()?;
+        let std_offset = -p.offset()?;
+        if p.0.is_empty() {
+            return Some(Self { std_offset, dst: None });
+        }
+        p.name()?;
+        let offset = if p.0.first() == Some(&b',') { std_offset + 3600 } else { -p.offset()? };
+        p.eat(b',')?;
+        let start = p.transition()?;
+        p.eat(b',')?;
+        let end = p.transition()?;
+        if !p.0.is_empty() {
+            return None;
+        }
+        Some(Self { std_offset, dst: Some(Dst { offset, start, end }) })
+    }
+
+    /// Returns the offset from UTC (in seconds east of UTC) at the given time.
+    fn offset_at(self, utc: i64) -> i64 {
+        let Some(dst) = self.dst else { return self.std_offset };
+        let (year, ..) = civil_from_days((utc + self.std_offset).div_euclid(SECS_PER_DAY));
+        // Transition times are given in the local time currently in effect.
+        let start = dst.start.0.days(year) * SECS_PER_DAY + dst.start.1 - self.std_offset;
+        let end = dst.end.0.days(year) * SECS_PER_DAY + dst.end.1 - dst.offset;
+        let in_dst = if start < end {
+            start <= utc && utc < end
+        } else {
+            // DST spans the new year (e.g., the southern hemisphere).
+            utc < end || start <= utc
+        };
+        if in_dst { dst.offset } else { self.std_offset }
+    }
+
+    /// Converts the given UTC time to local time.
+    pub(crate) fn local_from_utc(self, utc: i64) -> i64 {
+        utc + self.offset_at(utc)
+    }
+
+    /// Converts the given local time to UTC.
+    ///
+    /// If the local time is ambiguous (e.g., at the end of DST), the earlier
+    /// time is returned. If the local time does not exist (e.g., at the start of
+    /// DST), it is shifted forward by the length of the gap.
+    pub(crate) fn utc_from_local(self, local: i64) -> i64 {
+        let Some(dst) = self.dst else { return local - self.std_offset };
+        let candidates = [local - self.std_offset, local - dst.offset];
+        candidates
+            .into_iter()
+            .filter(|&utc| self.local_from_utc(utc) == local)
+            .min()
+            .unwrap_or_else(|| candidates[0].max(candidates[1]))
+    }
+}
+
+struct Parser<'a>(&'a [u8]);
+
+impl Parser<'_> {
+    fn eat(&mut self, b: u8) -> Option<()> {
+        let (&first, rest) = self.0.split_first()?;
+        if first != b {
+            return None;
+        }
+        self.0 = rest;
+        Some(())
+    }
+
+    /// Parses a time zone abbreviation, either alphabetic (`EST`) or quoted (`<+09>`).
+    fn name(&mut self) -> Option<()> {
+        let len = if self.0.first() == Some(&b'<') {
+            self.0.iter().position(|&b| b == b'>')? + 1
+        } else {
+            self.0.iter().position(|b| !b.is_ascii_alphabetic()).unwrap_or(self.0.len())
+        };
+        if len < 3 {
+            return None;
+        }
+        self.0 = &self.0[len..];
+        Some(())
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        let len = self.0.iter().position(|b| !b.is_ascii_digit()).unwrap_or(self.0.len());
+        if len == 0 {
+            return None;
+        }
+        let n = core::str::from_utf8(&self.0[..len]).ok()?.parse().ok()?;
+        self.0 = &self.0[len..];
+        Some(n)
+    }
+
+    /// Parses `[+-]hh[:mm[:ss]]` into seconds.
+    fn offset(&mut self) -> Option<i64> {
+        let sign = match self.0.first() {
+            Some(b'-') => {
+                self.0 = &self.0[1..];
+                -1
+            }
+            Some(b'+') => {
+                self.0 = &self.0[1..];
+                1
+            }
+            _ => 1,
+        };
+        let mut secs = self.number()? * 3600;
+        for unit in [60, 1] {
+            if self.eat(b':').is_none() {
+                break;
+            }
+            secs += self.number()? * unit;
+        }
+        Some(sign * secs)
+    }
+
+    /// Parses `date[/time]`.
+    fn transition(&mut self) -> Option<(TransitionDate, i64)> {
+        let date = match self.0.first()? {
+            b'J' => {
+                self.0 = &self.0[1..];
+                let n = u16::try_from(self.number()?).ok()?;
+                if !(1..=365).contains(&n) {
+                    return None;
+                }
+                TransitionDate::Julian1(n)
+            }
+            b'M' => {
+                self.0 = &self.0[1..];
+                let month = u8::try_from(self.number()?).ok()?;
+                self.eat(b'.')?;
+                let week = u8::try_from(self.number()?).ok()?;
+                self.eat(b'.')?;
+                let day = u8::try_from(self.number()?).ok()?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || day > 6 {
+                    return None;
+                }
+                TransitionDate::MonthWeekDay(month, week, day)
+            }
+            _ => {
+                let n = u16::try_from(self.number()?).ok()?;
+                if n > 365 {
+                    return None;
+                }
+                TransitionDate::Julian0(n)
+            }
+        };
+        let time = if self.eat(b'/').is_some() { self.offset()? } else { 2 * 3600 };
+        Some((date, time))
+    }
+}
@@ -18,6 +18,8 @@ use alloc::{
 use serde::de::{self, Deserialize, Deserializer};
 use serde_derive::{Deserialize, Serialize};
 
+use crate::error::Error;
+
 /// The Dependabot v1 configuration.
 ///
 /// See [Dependabot Docs][docs] for more.
@@ -62,9 +64,7 @@ where
     let n: u8 = Deserialize::deserialize(deserializer)?;
     match n {
         1 => Ok(n),
-        _ => Err(de::Error::custom(format!(
-            "The property 'version' value \"{n}\" did not match: 1",
-        ))),
+        _ => Err(de::Error::custom(Error::property("version", n, "did not match: 1"))),
     }
 }
 
@@ -405,6 +405,162 @@ pub enum AutomergedUpdateType {
     All,
 }
 
+/// Generates a GitHub Actions workflow that auto-merges pull requests
+/// matching any of the [`automerged_updates`](UpdateConfig::automerged_updates)
+/// rules of `config`, as version 1 did.
+///
+/// Each rule only matches pull requests of the package manager and the
+/// directory of the update configuration that defines it.
+///
+/// The returned workflow is intended to be saved as
+/// `.github/workflows/dependabot-automerge.yml`. It reads the metadata of the
+/// pull request using [`dependabot/fetch-metadata`][fetch-metadata], and
+/// enables auto-merge using `gh pr merge --auto`, so auto-merge must be allowed
+/// in the repository settings.
+///
+/// Some rules can only be approximated:
+///
+/// - `dependency_name` only matches pull requests updating a single
+///   dependency. Wildcards are matched by the parts between them, in any
+///   order.
+/// - The directory is compared with the one in the branch name of the pull
+///   request, which fetch-metadata reports with a leading `/` and without a
+///   trailing `/`.
+/// - `in_range` is treated as a SemVer minor or patch update, which is what
+///   it means for caret requirements.
+/// - `security:patch` requires the `github-token` of the metadata step to have
+///   access to Dependabot alerts, which the default `GITHUB_TOKEN` does not.
+///
+/// [fetch-metadata]: https://github.com/dependabot/fetch-metadata
+#[must_use]
+pub fn automerge_workflow(config: &Dependabot) -> String {
+    const OUTPUTS: &str = "steps.metadata.outputs";
+    let mut alert_lookup = false;
+    let rules: Vec<String> = config
+        .update_configs
+        .iter()
+        .flat_map(|config| {
+            config.automerged_updates.iter().flatten().map(move |u| (config, &u.match_))
+        })
+        .map(|(config, m)| {
+            let package_manager = crate::v2::package_ecosystem_from_v1(config.package_manager)
+                .map_or_else(|| "dep".to_string(), |ecosystem| ecosystem.package_manager());
+            let directory = format!("/{}", config.directory.trim_matches('/'));
+            let mut conditions = vec![
+                format!("{OUTPUTS}.package-ecosystem == {}", literal(&package_manager)),
+                format!("{OUTPUTS}.directory == {}", literal(&directory)),
+            ];
+            if let Some(name) = &m.dependency_name {
+                let parts: Vec<&str> = name.split('*').collect();
+                if let [name] = &*parts {
+                    conditions.push(format!("{OUTPUTS}.dependency-names == {}", literal(name)));
+                } else {
+                    // The names of multiple dependencies are separated by `, `.
+                    conditions.push(format!("!contains({OUTPUTS}.dependency-names, ',')"));
+                    let last = parts.len() - 1;
+                    for (i, part) in parts.into_iter().enumerate() {
+                        let function = match i {
+                            _ if part.is_empty() => continue,
+                            0 => "startsWith",
+                            _ if i == last => "endsWith",
+                            _ => "contains",
+                        };
+                        conditions.push(format!(
+                            "{function}({OUTPUTS}.dependency-names, {})",
+                            literal(part)
+                        ));
+                    }
+                }
+            }
+            match m.dependency_type {
+                Some(AutomergedDependencyType::Development) => {
+                    conditions.push(format!("{OUTPUTS}.dependency-type == 'direct:development'"));
+                }
+                Some(AutomergedDependencyType::Production) => {
+                    conditions.push(format!("{OUTPUTS}.dependency-type == 'direct:production'"));
+                }
+                Some(AutomergedDependencyType::All) | None => {}
+            }
+            let patch = format!("{OUTPUTS}.update-type == 'version-update:semver-patch'");
+            let minor = format!("{OUTPUTS}.update-type == 'version-update:semver-minor'");
+            match m.update_type {
+                Some(AutomergedUpdateType::SecurityPatch) => {
+                    alert_lookup = true;
+                    conditions.push(patch);
+                    conditions.push(format!("{OUTPUTS}.ghsa-id != ''"));
+                }
+                Some(AutomergedUpdateType::SemverPatch) => conditions.push(patch),
+                Some(AutomergedUpdateType::SemverMinor) => conditions.push(minor),
+                Some(AutomergedUpdateType::InRange) => {
+                    conditions.push(format!("({minor} || {patch})"));
+                }
+                Some(AutomergedUpdateType::All) | None => {}
+            }
+            conditions.join(" && ")
+        })
+        .collect();
+    let condition = match &*rules {
+        [] => "false".to_string(),
+        [rule] => rule.clone(),
+        rules => rules.iter().map(|rule| format!("({rule})")).collect::<Vec<_>>().join(" || "),
+    };
+    let alert_lookup = if alert_lookup { "\n          alert-lookup: true" } else { "" };
+    format!(
+        "name: Dependabot auto-merge
+
+on: pull_request
+
+permissions:
+  contents: write
+  pull-requests: write
+
+jobs:
+  automerge:
+    runs-on: ubuntu-latest
+    if: github.event.pull_request.user.login == 'dependabot[bot]'
+    steps:
+      - id: metadata
+        uses: dependabot/fetch-metadata@v2
+        with:
+          github-token: ${{{{ secrets.GITHUB_TOKEN }}}}{alert_lookup}
+      - if: {}
+        run: gh pr merge --auto --merge \"$PR_URL\"
+        env:
+          PR_URL: ${{{{ github.event.pull_request.html_url }}}}
+          GH_TOKEN: ${{{{ secrets.GITHUB_TOKEN }}}}
+",
+        double_quoted(&condition)
+    )
+}
+
+/// Returns `s` as a string literal of GitHub Actions expressions.
+fn literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Returns `s` as a double-quoted YAML scalar.
+fn double_quoted(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c.is_control() => {
+                // All control characters can be written as `\xXX`.
+                let c = u32::from(c);
+                out.push_str("\\x");
+                out.extend([c >> 4, c & 0xF].map(|d| char::from_digit(d, 16).unwrap_or_default()));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
 /// How to update manifest version requirements.
 ///
 /// See [Dependabot Docs][docs] for more.
@@ -7,7 +7,6 @@
 //! [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference
 
 // TODO: Update based on the latest docs
-// TODO: add groups once stabilized (currently in beta): https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/configuration-options-for-the-dependabot.yml-file#groups
 
 use alloc::{
     format,
@@ -15,24 +14,40 @@ use alloc::{
     vec,
     vec::Vec,
 };
-use core::fmt;
+use core::{fmt, marker::PhantomData, mem, str::FromStr};
+use std::{
+    path::{Component, Path},
+    time::SystemTime,
+};
 
 use indexmap::IndexMap;
-use serde::de::{self, Deserialize, Deserializer};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer as _, MapAccess},
+    ser::{Serialize, Serializer},
+};
 use serde_derive::{Deserialize, Serialize};
 
+use crate::{
+    Value, cst,
+    error::{Class, Error},
+    md5,
+    time::{self, TzRule},
+    v1, version,
+    yaml::{self, Locator, Segment},
+};
+
 /// The Dependabot v2 configuration.
 ///
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Dependabot {
     #[serde(deserialize_with = "de_version")]
-    version: u8,
+    pub(crate) version: u8,
     /// Opt in to updates for ecosystems that are not yet generally available.
     ///
     /// See [GitHub Docs][docs] for more.
@@ -45,6 +60,9 @@ pub struct Dependabot {
     pub registries: Registries,
     /// Configuration options for updates
     pub updates: Vec<Update>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
 impl Dependabot {
@@ -56,8 +74,509 @@ impl Dependabot {
             enable_beta_ecosystems: None,
             registries: Registries::default(),
             updates,
+            extra: IndexMap::new(),
+        }
+    }
+
+    /// Parses the configuration with the given [`ParseMode`].
+    ///
+    /// `s.parse::<Dependabot>()` is equivalent to
+    /// `Dependabot::parse(s, ParseMode::Lenient)`.
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Self, Error> {
+        let config: Self = s.parse()?;
+        if mode == ParseMode::Strict {
+            if let Some((path, field, expected)) = config.first_unknown_field() {
+                return Err(yaml::unknown_field(s, &path, field, expected));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Parses the configuration, collecting all errors instead of stopping at
+    /// the first one.
+    ///
+    /// Each entry of [`registries`](Self::registries) and
+    /// [`updates`](Self::updates) is parsed independently. Returns the
+    /// configuration with the erroneous entries omitted (or `None` if the
+    /// top-level properties are erroneous), and the errors in the order they
+    /// appear in the input.
+    #[must_use]
+    pub fn parse_all_errors(s: &str) -> (Option<Self>, Vec<Error>) {
+        let mut value: serde_yaml::Value = match serde_yaml::from_str(s) {
+            Ok(value) => value,
+            Err(e) => return (None, vec![Error::new(e)]),
+        };
+        let locator = Locator::new(s);
+        let mut errors = vec![];
+        let mut registries = None;
+        let mut updates = None;
+        let mut replaced = vec![];
+        if let serde_yaml::Value::Mapping(map) = &mut value {
+            if let Some(serde_yaml::Value::Mapping(entries)) = map.get_mut("registries") {
+                if entries.keys().all(serde_yaml::Value::is_string) {
+                    let mut parsed = Registries::default();
+                    for (name, registry) in mem::take(entries) {
+                        let serde_yaml::Value::String(name) = name else { continue };
+                        let path = [Segment::Key("registries"), Segment::Key(&name)];
+                        match locator.deserialize(&path, &registry, &[]) {
+                            Ok(registry) => {
+                                parsed.insert(name, registry);
+                            }
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                    registries = Some(parsed);
+                    replaced.push("registries");
+                }
+            }
+            if let Some(serde_yaml::Value::Sequence(entries)) = map.get_mut("updates") {
+                let mut parsed = vec![];
+                for (i, update) in mem::take(entries).iter().enumerate() {
+                    let path = [Segment::Key("updates"), Segment::Index(i)];
+                    match locator.deserialize(&path, update, &[]) {
+                        Ok(update) => parsed.push(update),
+                        Err(e) => errors.push(e),
+                    }
+                }
+                updates = Some(parsed);
+                replaced.push("updates");
+            }
+        }
+        let config = match locator.deserialize::<Self>(&[], &value, &replaced) {
+            Ok(mut config) => {
+                if let Some(registries) = registries {
+                    config.registries = registries;
+                }
+                if let Some(updates) = updates {
+                    config.updates = updates;
+                }
+                Some(config)
+            }
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+        errors.sort_by_key(|e| e.location().map(|location| location.index()));
+        (config, errors)
+    }
+
+    /// Checks the semantic rules that GitHub enforces in addition to the shape
+    /// of the configuration, and returns the violations found.
+    ///
+    /// Returns an empty vector if the configuration is valid.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut seen: Vec<(&PackageEcosystem, &str, Option<&str>, usize)> = vec![];
+        for (i, update) in self.updates.iter().enumerate() {
+            let directories: Vec<(&str, String)> = match (&update.directory, &update.directories) {
+                (Some(_), Some(_)) => {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticCode::ConflictingDirectory,
+                        "The properties 'directory' and 'directories' cannot be specified at the same time",
+                        format!("updates[{i}]"),
+                    ));
+                    vec![]
+                }
+                (None, None) => {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticCode::MissingDirectory,
+                        "Either the property 'directory' or 'directories' is required",
+                        format!("updates[{i}]"),
+                    ));
+                    vec![]
+                }
+                (Some(directory), None) => vec![(&**directory, format!("updates[{i}].directory"))],
+                (None, Some(directories)) if directories.is_empty() => {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticCode::EmptyDirectories,
+                        "The property 'directories' must contain at least one directory",
+                        format!("updates[{i}].directories"),
+                    ));
+                    vec![]
+                }
+                (None, Some(directories)) => directories
+                    .iter()
+                    .enumerate()
+                    .map(|(j, d)| (d.as_str(), format!("updates[{i}].directories[{j}]")))
+                    .collect(),
+            };
+            let target_branch = update.target_branch.as_deref();
+            for (directory, path) in directories {
+                if let Some(&(.., first)) = seen.iter().find(|&&(e, d, t, _)| {
+                    *e == update.package_ecosystem && d == directory && t == target_branch
+                }) {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticCode::DuplicateUpdate,
+                        format!(
+                            "The combination of 'package-ecosystem', 'directory', and 'target-branch' must be unique, but updates[{first}] has the same combination"
+                        ),
+                        path,
+                    ));
+                } else {
+                    seen.push((&update.package_ecosystem, directory, target_branch, i));
+                }
+            }
+            if let Some(UpdateRegistries::Names(names)) = &update.registries {
+                for (j, name) in names.iter().enumerate() {
+                    if !self.registries.contains_key(name) {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticCode::UndefinedRegistry,
+                            format!("The registry '{name}' is not defined in 'registries'"),
+                            format!("updates[{i}].registries[{j}]"),
+                        ));
+                    }
+                }
+            }
+            let schedule = &update.schedule;
+            if schedule.interval == Interval::Cron && schedule.cronjob.is_none() {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::MissingCronjob,
+                    "The property 'cronjob' is required when 'interval' is 'cron'",
+                    format!("updates[{i}].schedule"),
+                ));
+            }
+            if schedule.interval != Interval::Cron && schedule.cronjob.is_some() {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::UnexpectedCronjob,
+                    "The property 'cronjob' is only allowed when 'interval' is 'cron'",
+                    format!("updates[{i}].schedule.cronjob"),
+                ));
+            }
+            // `day` with other intervals is not reported because GitHub
+            // ignores it, and `timezone` applies to `cronjob`.
+            if schedule.interval == Interval::Cron && schedule.time.is_some() {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::UnexpectedTime,
+                    "The property 'time' is not allowed when 'interval' is 'cron'; specify the time in 'cronjob' instead",
+                    format!("updates[{i}].schedule.time"),
+                ));
+            }
+        }
+        diagnostics
+    }
+
+    /// Converts a version 1 configuration to version 2, and returns the parts
+    /// of it that have no version 2 equivalent.
+    ///
+    /// Such parts are dropped, or replaced with the closest equivalent (e.g.,
+    /// the `live` schedule is replaced with `daily`). Use [`TryFrom`] instead
+    /// to reject configurations that cannot be converted without loss.
+    #[must_use]
+    pub fn from_v1(config: v1::Dependabot) -> (Self, Vec<LossyItem>) {
+        let mut lossy = vec![];
+        let mut updates = vec![];
+        for (i, v1) in config.update_configs.into_iter().enumerate() {
+            let Some(package_ecosystem) = package_ecosystem_from_v1(v1.package_manager) else {
+                lossy.push(LossyItem::new(
+                    LossyItemCode::GoDep,
+                    "The package manager 'go:dep' is not supported in version 2; the update configuration is dropped",
+                    format!("update_configs[{i}].package_manager"),
+                ));
+                continue;
+            };
+            let interval = match v1.update_schedule {
+                v1::UpdateSchedule::Live => {
+                    lossy.push(LossyItem::new(
+                        LossyItemCode::LiveSchedule,
+                        "The schedule 'live' is not supported in version 2; 'daily' is used instead",
+                        format!("update_configs[{i}].update_schedule"),
+                    ));
+                    Interval::Daily
+                }
+                v1::UpdateSchedule::Daily => Interval::Daily,
+                v1::UpdateSchedule::Weekly => Interval::Weekly,
+                v1::UpdateSchedule::Monthly => Interval::Monthly,
+            };
+            let mut update = Update::new(package_ecosystem, v1.directory, Schedule::new(interval));
+            update.target_branch = v1.target_branch;
+            update.reviewers = v1.default_reviewers;
+            update.assignees = v1.default_assignees;
+            update.labels = v1.default_labels;
+            update.milestone = v1.default_milestone;
+            if let Some(allowed_updates) = v1.allowed_updates {
+                let mut allow = vec![];
+                let mut security_only = vec![];
+                for (j, allowed) in allowed_updates.into_iter().enumerate() {
+                    let allowed = allowed.match_;
+                    if allowed.update_type == Some(v1::AllowedUpdateType::Security) {
+                        security_only.push(j);
+                        continue;
+                    }
+                    allow.push(Allow {
+                        dependency_name: allowed.dependency_name,
+                        dependency_type: allowed.dependency_type.map(|t| match t {
+                            v1::AllowedDependencyType::Development => DependencyType::Development,
+                            v1::AllowedDependencyType::Production => DependencyType::Production,
+                            v1::AllowedDependencyType::Direct => DependencyType::Direct,
+                            v1::AllowedDependencyType::Indirect => DependencyType::Indirect,
+                            v1::AllowedDependencyType::All => DependencyType::All,
+                        }),
+                        extra: IndexMap::new(),
+                    });
+                }
+                let message = if allow.is_empty() && !security_only.is_empty() {
+                    // Without `allow`, all direct dependencies would get version updates.
+                    update.open_pull_requests_limit = Some(0);
+                    "The update type 'security' is not supported in version 2; the entry is dropped, and 'open-pull-requests-limit' is set to 0 to only receive security updates"
+                } else {
+                    "The update type 'security' is not supported in version 2; the entry is dropped"
+                };
+                for j in security_only {
+                    lossy.push(LossyItem::new(
+                        LossyItemCode::SecurityOnlyUpdates,
+                        message,
+                        format!("update_configs[{i}].allowed_updates[{j}].match.update_type"),
+                    ));
+                }
+                if !allow.is_empty() {
+                    update.allow = Some(allow);
+                }
+            }
+            update.ignore = v1.ignored_updates.map(|ignored_updates| {
+                ignored_updates
+                    .into_iter()
+                    .map(|ignored| {
+                        let mut ignore = Ignore::new(ignored.match_.dependency_name);
+                        ignore.versions = ignored.match_.version_requirement.map(|v| vec![v]);
+                        ignore
+                    })
+                    .collect()
+            });
+            if v1.automerged_updates.is_some() {
+                lossy.push(LossyItem::new(
+                    LossyItemCode::AutomergedUpdates,
+                    "The property 'automerged_updates' is not supported in version 2; use a GitHub Actions workflow instead",
+                    format!("update_configs[{i}].automerged_updates"),
+                ));
+            }
+            update.versioning_strategy = v1.version_requirement_updates.map(|v| match v {
+                v1::VersionRequirementUpdate::Off => VersioningStrategy::LockfileOnly,
+                v1::VersionRequirementUpdate::Auto => VersioningStrategy::Auto,
+                v1::VersionRequirementUpdate::WidenRanges => VersioningStrategy::Widen,
+                v1::VersionRequirementUpdate::IncreaseVersions => VersioningStrategy::Increase,
+                v1::VersionRequirementUpdate::IncreaseVersionsIfNecessary => {
+                    VersioningStrategy::IncreaseIfNecessary
+                }
+            });
+            update.commit_message = v1.commit_message.map(|v1| CommitMessage {
+                prefix: v1.prefix,
+                prefix_development: v1.prefix_development,
+                include: (v1.include_scope == Some(true)).then_some(CommitMessageInclude::Scope),
+                extra: IndexMap::new(),
+            });
+            updates.push(update);
+        }
+        (Self::new(updates), lossy)
+    }
+}
+
+pub(crate) fn package_ecosystem_from_v1(
+    package_manager: v1::PackageManager,
+) -> Option<PackageEcosystem> {
+    Some(match package_manager {
+        v1::PackageManager::Javascript => PackageEcosystem::Npm,
+        v1::PackageManager::RubyBundler => PackageEcosystem::Bundler,
+        v1::PackageManager::PhpComposer => PackageEcosystem::Composer,
+        v1::PackageManager::Python => PackageEcosystem::Pip,
+        v1::PackageManager::GoModules => PackageEcosystem::Gomod,
+        v1::PackageManager::GoDep => return None,
+        v1::PackageManager::JavaMaven => PackageEcosystem::Maven,
+        v1::PackageManager::JavaGradle => PackageEcosystem::Gradle,
+        v1::PackageManager::DotnetNuget => PackageEcosystem::Nuget,
+        v1::PackageManager::RustCargo => PackageEcosystem::Cargo,
+        v1::PackageManager::ElixirHex => PackageEcosystem::Mix,
+        v1::PackageManager::Docker => PackageEcosystem::Docker,
+        v1::PackageManager::Terraform => PackageEcosystem::Terraform,
+        v1::PackageManager::Submodules => PackageEcosystem::Gitsubmodule,
+        v1::PackageManager::Elm => PackageEcosystem::Elm,
+    })
+}
+
+/// Fails if the configuration cannot be converted without loss. See
+/// [`Dependabot::from_v1`] for a lossy conversion.
+impl TryFrom<v1::Dependabot> for Dependabot {
+    type Error = LossyConversionError;
+
+    fn try_from(config: v1::Dependabot) -> Result<Self, Self::Error> {
+        let (config, lossy) = Self::from_v1(config);
+        if lossy.is_empty() { Ok(config) } else { Err(LossyConversionError { lossy }) }
+    }
+}
+
+/// How to handle properties that are not known to this crate.
+///
+/// Unknown properties are kept in the `extra` field of the struct that
+/// contains them (e.g., [`Update::extra`](Update#structfield.extra)), so that
+/// they are not lost when the configuration is re-serialized.
+///
+/// The mode only applies to [`Dependabot::parse`]. Parsing any type with
+/// [`FromStr`] or serde, including [`Dependabot`] and nested types such as
+/// [`Update`], always uses [`ParseMode::Lenient`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum ParseMode {
+    /// Reject unknown properties.
+    Strict,
+    /// Accept unknown properties. This is the mode used by [`FromStr`].
+    #[default]
+    Lenient,
+}
+
+/// An unknown property: the path to the mapping that contains it, its name,
+/// and the names of the known properties of the mapping.
+type UnknownField<'a> = (Vec<Segment<'a>>, &'a str, &'static [&'static str]);
+
+/// Structs that keep unknown properties in the `extra` field.
+///
+/// Implemented by the generated code in src/gen/extra.rs, which takes the
+/// names of the known properties from the serde attributes of the struct.
+/// The generated code also implements `Deserialize` for the struct with
+/// [`deserialize_extra`].
+pub(crate) trait Extra {
+    const FIELDS: &'static [&'static str];
+    fn extra(&self) -> &IndexMap<String, Value>;
+}
+
+/// Deserializes the mapping of the struct `name` into `T`, which has the
+/// known properties of the struct, and the unknown properties.
+///
+/// Unlike `#[serde(flatten)]`, which buffers unknown properties in a
+/// representation that cannot hold tagged values, this deserializes them
+/// directly as [`Value`].
+pub(crate) fn deserialize_extra<'de, T, D>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str],
+) -> Result<(T, IndexMap<String, Value>), D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct Visitor<T> {
+        name: &'static str,
+        fields: &'static [&'static str],
+        marker: PhantomData<T>,
+    }
+
+    impl<'de, T: Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+        type Value = (T, IndexMap<String, Value>);
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "struct {}", self.name)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut extra = IndexMap::new();
+            let map = KnownFields { map, fields: self.fields, extra: &mut extra };
+            let value = T::deserialize(de::value::MapAccessDeserializer::new(map))?;
+            Ok((value, extra))
+        }
+    }
+
+    /// Passes the entries of known properties through, and collects the
+    /// others into `extra`.
+    struct KnownFields<'a, A> {
+        map: A,
+        fields: &'static [&'static str],
+        extra: &'a mut IndexMap<String, Value>,
+    }
+
+    impl<'de, A: MapAccess<'de>> MapAccess<'de> for KnownFields<'_, A> {
+        type Error = A::Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            while let Some(key) = self.map.next_key::<String>()? {
+                if self.fields.contains(&&*key) {
+                    return seed.deserialize(key.into_deserializer()).map(Some);
+                }
+                let value = self.map.next_value()?;
+                self.extra.insert(key, value);
+            }
+            Ok(None)
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            self.map.next_value_seed(seed)
         }
     }
+
+    deserializer.deserialize_map(Visitor { name, fields, marker: PhantomData })
+}
+
+fn unknown_field<'a, T: Extra>(path: Vec<Segment<'a>>, value: &'a T) -> Option<UnknownField<'a>> {
+    let field = value.extra().keys().next()?;
+    Some((path, field, T::FIELDS))
+}
+
+fn join<'a>(path: &[Segment<'a>], rest: &[Segment<'a>]) -> Vec<Segment<'a>> {
+    let mut path = path.to_vec();
+    path.extend_from_slice(rest);
+    path
+}
+
+impl Dependabot {
+    fn first_unknown_field(&self) -> Option<UnknownField<'_>> {
+        use Segment::{Index, Key};
+        unknown_field(vec![], self)
+            .or_else(|| {
+                self.registries.iter().find_map(|(name, registry)| {
+                    unknown_field(vec![Key("registries"), Key(name)], registry)
+                })
+            })
+            .or_else(|| {
+                self.updates
+                    .iter()
+                    .enumerate()
+                    .find_map(|(i, update)| update.first_unknown_field(&[Key("updates"), Index(i)]))
+            })
+    }
+}
+
+impl Update {
+    fn first_unknown_field<'a>(&'a self, path: &[Segment<'a>]) -> Option<UnknownField<'a>> {
+        use Segment::{Index, Key};
+        unknown_field(path.to_vec(), self)
+            .or_else(|| unknown_field(join(path, &[Key("schedule")]), &self.schedule))
+            .or_else(|| {
+                self.allow.iter().flatten().enumerate().find_map(|(i, allow)| {
+                    unknown_field(join(path, &[Key("allow"), Index(i)]), allow)
+                })
+            })
+            .or_else(|| {
+                let commit_message = self.commit_message.as_ref()?;
+                unknown_field(join(path, &[Key("commit-message")]), commit_message)
+            })
+            .or_else(|| unknown_field(join(path, &[Key("cooldown")]), self.cooldown.as_ref()?))
+            .or_else(|| {
+                self.groups.iter().flatten().find_map(|(name, group)| {
+                    unknown_field(join(path, &[Key("groups"), Key(name)]), group)
+                })
+            })
+            .or_else(|| {
+                self.ignore.iter().flatten().enumerate().find_map(|(i, ignore)| {
+                    unknown_field(join(path, &[Key("ignore"), Index(i)]), ignore)
+                })
+            })
+            .or_else(|| {
+                let pull_request_branch_name = self.pull_request_branch_name.as_ref()?;
+                unknown_field(
+                    join(path, &[Key("pull-request-branch-name")]),
+                    pull_request_branch_name,
+                )
+            })
+    }
 }
 
 impl Default for Dependabot {
@@ -69,20 +588,423 @@ impl Default for Dependabot {
 #[allow(clippy::to_string_trait_impl)]
 impl ToString for Dependabot {
     fn to_string(&self) -> String {
-        serde_yaml::to_string(&self).unwrap()
+        crate::emit::to_string(self).unwrap()
+    }
+}
+
+/// Formats a version 2 configuration file in the canonical style.
+///
+/// In the canonical style:
+///
+/// - Properties are ordered as the fields of the corresponding types (e.g.,
+///   [`Update`]), followed by unknown properties in their original order.
+/// - `updates` are sorted by `package-ecosystem`, then by `directory` (or the
+///   first of `directories`). Updates that compare equal keep their order.
+/// - Strings are quoted only when needed, including strings that YAML 1.1
+///   parsers interpret as booleans or numbers (e.g., `yes` and `10:30`).
+/// - Nested mappings and sequences are indented by two spaces.
+///
+/// The [`ToString`] implementation of [`Dependabot`] uses the same style,
+/// except that it doesn't sort `updates`.
+///
+/// Comments are not preserved. Use [`Document`] to edit a file without
+/// reformatting it.
+///
+/// # Examples
+///
+/// ```
+/// use dependabot_config::v2;
+///
+/// let s = "\
+/// version: 2
+/// updates:
+/// - package-ecosystem: 'npm'
+///   schedule: { interval: 'daily' }
+///   directory: '/'
+/// - directory: '/'
+///   package-ecosystem: 'cargo'
+///   schedule: { interval: 'daily' }
+/// ";
+/// assert_eq!(
+///     v2::format(s).unwrap(),
+///     "\
+/// version: 2
+/// updates:
+///   - package-ecosystem: cargo
+///     directory: /
+///     schedule:
+///       interval: daily
+///   - package-ecosystem: npm
+///     directory: /
+///     schedule:
+///       interval: daily
+/// "
+/// );
+/// ```
+pub fn format(s: &str) -> Result<String, Error> {
+    let mut config: Dependabot = s.parse()?;
+    config.updates.sort_by_cached_key(|update| {
+        let directory = update
+            .directory
+            .clone()
+            .or_else(|| Some(update.directories.as_ref()?.first()?.to_string()));
+        (update.package_ecosystem.to_string(), directory)
+    });
+    crate::emit::to_string(&config)
+}
+
+/// A violation of a semantic rule found by [`Dependabot::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// The kind of the violation.
+    pub code: DiagnosticCode,
+    /// A human-readable description of the violation.
+    pub message: String,
+    /// The path to the offending value, e.g., `updates[3].schedule`.
+    pub path: String,
+}
+
+impl Diagnostic {
+    fn new(code: DiagnosticCode, message: impl Into<String>, path: String) -> Self {
+        Self { code, message: message.into(), path }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The kind of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum DiagnosticCode {
+    /// Both `directory` and `directories` are specified.
+    ConflictingDirectory,
+    /// Neither `directory` nor `directories` is specified.
+    MissingDirectory,
+    /// `directories` is empty.
+    EmptyDirectories,
+    /// Multiple updates have the same combination of `package-ecosystem`,
+    /// `directory`, and `target-branch`.
+    DuplicateUpdate,
+    /// The `cron` interval is specified without `cronjob`.
+    MissingCronjob,
+    /// `cronjob` is specified with an interval other than `cron`.
+    UnexpectedCronjob,
+    /// `time` is specified with the `cron` interval.
+    UnexpectedTime,
+    /// `registries` of an update refers to a registry that is not defined in
+    /// the top-level `registries`.
+    UndefinedRegistry,
+}
+
+/// A part of a version 1 configuration that has no version 2 equivalent,
+/// reported by [`Dependabot::from_v1`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct LossyItem {
+    /// The kind of the item.
+    pub code: LossyItemCode,
+    /// A human-readable description of the item and how it was converted.
+    pub message: String,
+    /// The path to the item in the version 1 configuration, e.g.,
+    /// `update_configs[0].update_schedule`.
+    pub path: String,
+}
+
+impl LossyItem {
+    fn new(code: LossyItemCode, message: impl Into<String>, path: String) -> Self {
+        Self { code, message: message.into(), path }
+    }
+}
+
+impl fmt::Display for LossyItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The error returned by the [`TryFrom`] conversion from a version 1
+/// configuration that cannot be converted to version 2 without loss.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LossyConversionError {
+    /// The parts of the configuration that have no version 2 equivalent, as
+    /// reported by [`Dependabot::from_v1`].
+    pub lossy: Vec<LossyItem>,
+}
+
+impl fmt::Display for LossyConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the configuration cannot be converted to version 2 without loss: ")?;
+        for (i, item) in self.lossy.iter().enumerate() {
+            if i != 0 {
+                f.write_str("; ")?;
+            }
+            fmt::Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for LossyConversionError {}
+
+/// The kind of a [`LossyItem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum LossyItemCode {
+    /// The `live` schedule, which is replaced with `daily`.
+    LiveSchedule,
+    /// `automerged_updates`, which is dropped. See [`v1::automerge_workflow`]
+    /// for a replacement.
+    AutomergedUpdates,
+    /// The `go:dep` package manager, whose update configuration is dropped.
+    GoDep,
+    /// An entry of `allowed_updates` with `update_type: security`, which is
+    /// dropped. If all entries are such, `open-pull-requests-limit` is set to
+    /// 0 to disable version updates.
+    SecurityOnlyUpdates,
+}
+
+/// A version 2 configuration file that preserves comments and formatting when
+/// edited.
+///
+/// Converting [`Dependabot`] back to a string discards comments, blank lines,
+/// and quoting. Editing through this type instead only rewrites the
+/// properties that changed, and leaves the rest of the file as is.
+///
+/// Only block style YAML is supported; anchors, aliases, and tags are
+/// rejected.
+///
+/// # Examples
+///
+/// ```
+/// use dependabot_config::v2::Document;
+///
+/// let mut doc: Document = "\
+/// version: 2
+/// updates:
+///   - package-ecosystem: cargo # Rust dependencies
+///     directory: /
+///     schedule:
+///       interval: daily # every weekday
+/// "
+/// .parse()
+/// .unwrap();
+/// let mut update = doc.update_mut(0).unwrap();
+/// update.set_labels(Some(vec!["dependencies".to_owned()])).unwrap();
+/// assert_eq!(
+///     doc.to_string(),
+///     "\
+/// version: 2
+/// updates:
+///   - package-ecosystem: cargo # Rust dependencies
+///     directory: /
+///     schedule:
+///       interval: daily # every weekday
+///     labels:
+///       - dependencies
+/// "
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Document {
+    src: String,
+    root: cst::Mapping,
+    config: Dependabot,
+}
+
+impl Document {
+    /// Returns the configuration represented by this document.
+    #[must_use]
+    pub fn config(&self) -> &Dependabot {
+        &self.config
+    }
+
+    /// Sets [`Dependabot::enable_beta_ecosystems`]. If `None`, the property
+    /// is removed.
+    pub fn set_enable_beta_ecosystems(&mut self, value: Option<bool>) -> Result<(), Error> {
+        let value = to_yaml(value)?;
+        self.edit(|src, root| {
+            cst::set(src, root, "enable-beta-ecosystems", value.as_ref(), Dependabot::FIELDS)
+        })
+    }
+
+    /// Sets [`Dependabot::registries`]. If empty, the property is removed.
+    pub fn set_registries(&mut self, registries: &Registries) -> Result<(), Error> {
+        let value = to_yaml(Some(registries).filter(|r| !r.is_empty()))?;
+        self.edit(|src, root| cst::set(src, root, "registries", value.as_ref(), Dependabot::FIELDS))
+    }
+
+    /// Returns a handle to edit the update configuration at `index`, or `None`
+    /// if `index` is out of bounds.
+    pub fn update_mut(&mut self, index: usize) -> Option<UpdateMut<'_>> {
+        (index < self.config.updates.len()).then_some(UpdateMut { doc: self, index })
+    }
+
+    /// Appends an update configuration.
+    pub fn push_update(&mut self, update: &Update) -> Result<(), Error> {
+        let value = serde_yaml::to_value(update).map_err(Error::new)?;
+        let mut updates = self.config.updates.clone();
+        updates.push(update.clone());
+        let updates = to_yaml(Some(&updates))?;
+        self.edit(|src, root| match root.get("updates").and_then(|e| e.value.as_ref()) {
+            Some(cst::Node::Sequence(sequence)) => cst::push(src, sequence, &value),
+            // Empty or flow style.
+            _ => cst::set(src, root, "updates", updates.as_ref(), Dependabot::FIELDS),
+        })
+    }
+
+    /// Removes the update configuration at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_update(&mut self, index: usize) -> Result<Update, Error> {
+        let len = self.config.updates.len();
+        assert!(index < len, "removal index (is {index}) should be < len (is {len})");
+        let mut updates = self.config.updates.clone();
+        let removed = updates.remove(index);
+        let updates = to_yaml(Some(&updates))?;
+        self.edit(|src, root| match root.get("updates").and_then(|e| e.value.as_ref()) {
+            Some(cst::Node::Sequence(sequence)) if len > 1 => Ok(cst::remove(src, sequence, index)),
+            _ => cst::set(src, root, "updates", updates.as_ref(), Dependabot::FIELDS),
+        })?;
+        Ok(removed)
+    }
+
+    /// Applies an edit to the source and re-parses it. On error, the document
+    /// is left unchanged.
+    fn edit(
+        &mut self,
+        f: impl FnOnce(&str, &cst::Mapping) -> Result<String, Error>,
+    ) -> Result<(), Error> {
+        *self = f(&self.src, &self.root)?.parse()?;
+        Ok(())
+    }
+}
+
+impl FromStr for Document {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config = s.parse()?;
+        let root = cst::parse(s)?;
+        Ok(Self { src: s.into(), root, config })
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.src)
+    }
+}
+
+fn to_yaml<T: Serialize>(value: Option<T>) -> Result<Option<serde_yaml::Value>, Error> {
+    value.map(serde_yaml::to_value).transpose().map_err(Error::new)
+}
+
+/// A handle to edit an update configuration in a [`Document`].
+///
+/// This is created by [`Document::update_mut`].
+#[derive(Debug)]
+pub struct UpdateMut<'a> {
+    doc: &'a mut Document,
+    index: usize,
+}
+
+macro_rules! update_setters {
+    ($($name:ident($field:ident: $ty:ty) => $key:literal,)*) => {
+        impl UpdateMut<'_> {
+            $(
+                #[doc = concat!("Sets [`Update::", stringify!($field), "`]. If `None`, the property is removed.")]
+                pub fn $name(&mut self, $field: Option<$ty>) -> Result<(), Error> {
+                    self.set($key, $field)
+                }
+            )*
+        }
+    };
+}
+
+update_setters! {
+    set_directory(directory: String) => "directory",
+    set_directories(directories: Vec<PathPattern>) => "directories",
+    set_allow(allow: Vec<Allow>) => "allow",
+    set_assignees(assignees: Vec<String>) => "assignees",
+    set_commit_message(commit_message: CommitMessage) => "commit-message",
+    set_cooldown(cooldown: Cooldown) => "cooldown",
+    set_exclude_paths(exclude_paths: Vec<PathPattern>) => "exclude-paths",
+    set_groups(groups: IndexMap<String, Group>) => "groups",
+    set_ignore(ignore: Vec<Ignore>) => "ignore",
+    set_insecure_external_code_execution(
+        insecure_external_code_execution: InsecureExternalCodeExecution
+    ) => "insecure-external-code-execution",
+    set_labels(labels: Vec<String>) => "labels",
+    set_milestone(milestone: u32) => "milestone",
+    set_open_pull_requests_limit(open_pull_requests_limit: u32) => "open-pull-requests-limit",
+    set_pull_request_branch_name(
+        pull_request_branch_name: PullRequestBranchName
+    ) => "pull-request-branch-name",
+    set_rebase_strategy(rebase_strategy: RebaseStrategy) => "rebase-strategy",
+    set_registries(registries: UpdateRegistries) => "registries",
+    set_reviewers(reviewers: Vec<String>) => "reviewers",
+    set_target_branch(target_branch: String) => "target-branch",
+    set_vendor(vendor: bool) => "vendor",
+    set_versioning_strategy(versioning_strategy: VersioningStrategy) => "versioning-strategy",
+}
+
+impl UpdateMut<'_> {
+    /// Returns the update configuration.
+    #[must_use]
+    pub fn get(&self) -> &Update {
+        &self.doc.config.updates[self.index]
+    }
+
+    /// Sets [`Update::package_ecosystem`].
+    pub fn set_package_ecosystem(
+        &mut self,
+        package_ecosystem: PackageEcosystem,
+    ) -> Result<(), Error> {
+        self.set("package-ecosystem", Some(package_ecosystem))
+    }
+
+    /// Sets [`Update::schedule`].
+    pub fn set_schedule(&mut self, schedule: Schedule) -> Result<(), Error> {
+        self.set("schedule", Some(schedule))
+    }
+
+    fn set<T: Serialize>(&mut self, key: &str, value: Option<T>) -> Result<(), Error> {
+        let value = to_yaml(value)?;
+        let index = self.index;
+        self.doc.edit(|src, root| {
+            let update = match root.get("updates").and_then(|e| e.value.as_ref()) {
+                Some(cst::Node::Sequence(sequence)) => sequence.items[index].value.as_ref(),
+                _ => None,
+            };
+            let Some(cst::Node::Mapping(update)) = update else {
+                return Err(Error::parse(
+                    Class::Other,
+                    format!("updates[{index}] cannot be edited because it is not a block mapping"),
+                ));
+            };
+            cst::set(src, update, key, value.as_ref(), Update::FIELDS)
+        })
     }
 }
 
-fn de_version<'de, D>(deserializer: D) -> Result<u8, D::Error>
+pub(crate) fn de_version<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
     D: Deserializer<'de>,
 {
     let n: u8 = Deserialize::deserialize(deserializer)?;
     match n {
         2 => Ok(n),
-        _ => Err(de::Error::custom(format!(
-            "The property 'version' value \"{n}\" did not match: 2",
-        ))),
+        _ => Err(de::Error::custom(Error::property("version", n, "did not match: 2"))),
     }
 }
 
@@ -91,9 +1013,9 @@ where
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#configuration-options-for-updates
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Update {
     /// Package manager to use.
@@ -111,7 +1033,7 @@ pub struct Update {
     ///
     /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#directories
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub directories: Option<Vec<String>>,
+    pub directories: Option<Vec<PathPattern>>,
     /// How often to check for updates.
     pub schedule: Schedule,
     /// Customize which updates are allowed.
@@ -127,6 +1049,27 @@ pub struct Update {
     /// Commit message preferences.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commit_message: Option<CommitMessage>,
+    /// Delay updates for newly released versions.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#cooldown-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cooldown: Option<Cooldown>,
+    /// Exclude files and directories from updates.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#exclude-paths-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_paths: Option<Vec<PathPattern>>,
+    /// Group updates for multiple dependencies into a single pull request.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#groups--
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<IndexMap<String, Group>>,
     /// Ignore certain dependencies or versions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ignore: Option<Vec<Ignore>>,
@@ -160,6 +1103,13 @@ pub struct Update {
     /// Disable automatic rebasing.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rebase_strategy: Option<RebaseStrategy>,
+    /// Private registries that Dependabot can access when updating dependencies.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#registries--
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registries: Option<UpdateRegistries>,
     /// Reviewers to set on pull requests.
     ///
     /// See [GitHub Docs][docs] for more.
@@ -184,6 +1134,9 @@ pub struct Update {
     /// How to update manifest version requirements.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub versioning_strategy: Option<VersioningStrategy>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
 impl Update {
@@ -202,6 +1155,9 @@ impl Update {
             allow: None,
             assignees: None,
             commit_message: None,
+            cooldown: None,
+            exclude_paths: None,
+            groups: None,
             ignore: None,
             insecure_external_code_execution: None,
             labels: None,
@@ -209,40 +1165,416 @@ impl Update {
             open_pull_requests_limit: None,
             pull_request_branch_name: None,
             rebase_strategy: None,
+            registries: None,
             reviewers: None,
             target_branch: None,
             vendor: None,
             versioning_strategy: None,
+            extra: IndexMap::new(),
+        }
+    }
+
+    /// Returns `true` if the given manifest directory is covered by
+    /// [`directory`](Self::directory) or [`directories`](Self::directories),
+    /// and is not excluded by [`exclude_paths`](Self::exclude_paths).
+    ///
+    /// A directory is excluded if it or any of its ancestors matches an entry
+    /// of `exclude_paths`. The path is interpreted relative to the repository
+    /// root.
+    #[must_use]
+    pub fn matches_directory(&self, path: &Path) -> bool {
+        let Some(path_segments) = path_segments(path) else { return false };
+        let included = self.directory.as_deref().is_some_and(|directory| {
+            directory
+                .split('/')
+                .filter(|s| !s.is_empty() && *s != ".")
+                .eq(path_segments.iter().copied())
+        }) || self.directories.iter().flatten().any(|pattern| pattern.matches(path));
+        included
+            && !self
+                .exclude_paths
+                .iter()
+                .flatten()
+                .any(|pattern| path.ancestors().any(|ancestor| pattern.matches(ancestor)))
+    }
+
+    /// Returns the private registries of `config` that this update can access.
+    ///
+    /// Names that are not defined in [`Dependabot::registries`] are ignored;
+    /// use [`Dependabot::validate`] to detect them.
+    #[must_use]
+    pub fn resolve_registries<'a>(&self, config: &'a Dependabot) -> Vec<&'a Registry> {
+        match &self.registries {
+            None => vec![],
+            Some(UpdateRegistries::All) => config.registries.values().collect(),
+            Some(UpdateRegistries::Names(names)) => {
+                names.iter().filter_map(|name| config.registries.get(name)).collect()
+            }
+        }
+    }
+
+    /// Evaluates whether Dependabot would open a version update for the
+    /// dependency described by `candidate`, and explains which rule decided
+    /// it.
+    ///
+    /// The rules are checked in the following order, and the first one that
+    /// prevents the update decides:
+    ///
+    /// 1. The new version must be newer than the current version.
+    /// 2. The dependency must match an entry of [`allow`](Self::allow). If
+    ///    `allow` is not specified, only direct dependencies are updated.
+    /// 3. The dependency must not be ignored by an entry of
+    ///    [`ignore`](Self::ignore): by name only, by
+    ///    [`versions`](Ignore::versions) of the new version, or by
+    ///    [`update_types`](Ignore::update_types).
+    /// 4. If [`versioning_strategy`](Self::versioning_strategy) is
+    ///    `lockfile-only`, the new version must satisfy
+    ///    [`Candidate::requirement`].
+    /// 5. The number of [open pull requests](Candidate::open_pull_requests)
+    ///    must be less than [`open_pull_requests_limit`](Self::open_pull_requests_limit)
+    ///    (5 by default).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a version or requirement of `candidate`, or an
+    /// entry of [`Ignore::versions`], cannot be parsed.
+    pub fn evaluate(&self, candidate: &Candidate) -> Result<Decision, Error> {
+        let ecosystem = &self.package_ecosystem;
+        let current = Version::parse(ecosystem, &candidate.current_version)?;
+        let new = Version::parse(ecosystem, &candidate.new_version)?;
+        let name = &candidate.name;
+        if new <= current {
+            return Ok(Decision::skip(
+                DecisionReason::NotNewer,
+                format!("The version {new} is not newer than the current version {current}"),
+                None,
+            ));
+        }
+        let allowed_by = match &self.allow {
+            Some(allow) => match allow.iter().position(|allow| allow.matches(candidate)) {
+                Some(i) => Some(format!("allow[{i}]")),
+                None => {
+                    return Ok(Decision::skip(
+                        DecisionReason::NotAllowed,
+                        format!("The dependency '{name}' doesn't match any entry of 'allow'"),
+                        Some("allow".into()),
+                    ));
+                }
+            },
+            None if !candidate.direct => {
+                return Ok(Decision::skip(
+                    DecisionReason::NotAllowed,
+                    format!(
+                        "The dependency '{name}' is indirect, and indirect dependencies are only updated when allowed by 'allow'"
+                    ),
+                    None,
+                ));
+            }
+            None => None,
+        };
+        for (i, ignore) in self.ignore.iter().flatten().enumerate() {
+            if !ignore.name_pattern().matches(name) {
+                continue;
+            }
+            if ignore.versions.is_none() && ignore.update_types.is_none() {
+                return Ok(Decision::skip(
+                    DecisionReason::Ignored,
+                    format!("All updates of the dependency '{name}' are ignored"),
+                    Some(format!("ignore[{i}]")),
+                ));
+            }
+            for (j, requirement) in ignore.versions.iter().flatten().enumerate() {
+                if VersionRequirement::parse(ecosystem, requirement)?.matches(&new) {
+                    return Ok(Decision::skip(
+                        DecisionReason::Ignored,
+                        format!("The version {new} matches the ignored versions '{requirement}'"),
+                        Some(format!("ignore[{i}].versions[{j}]")),
+                    ));
+                }
+            }
+            let update_type = UpdateType::between(ecosystem, &current, &new);
+            if let Some((j, update_type)) = ignore
+                .update_types
+                .iter()
+                .flatten()
+                .enumerate()
+                .find(|&(_, &t)| Some(t) == update_type)
+            {
+                return Ok(Decision::skip(
+                    DecisionReason::Ignored,
+                    format!(
+                        "The update from {current} to {new} is a '{update_type}' update, which is ignored"
+                    ),
+                    Some(format!("ignore[{i}].update-types[{j}]")),
+                ));
+            }
+        }
+        if let (Some(VersioningStrategy::LockfileOnly), Some(requirement)) =
+            (&self.versioning_strategy, &candidate.requirement)
+        {
+            if !VersionRequirement::parse(ecosystem, requirement)?.matches(&new) {
+                return Ok(Decision::skip(
+                    DecisionReason::LockfileOnly,
+                    format!(
+                        "The version {new} doesn't satisfy the requirement '{requirement}', and only lockfile updates are allowed"
+                    ),
+                    Some("versioning-strategy".into()),
+                ));
+            }
+        }
+        let limit = self.open_pull_requests_limit.unwrap_or(5);
+        if candidate.open_pull_requests >= limit {
+            let message = if limit == 0 {
+                "Version updates are disabled because 'open-pull-requests-limit' is 0".into()
+            } else {
+                format!(
+                    "{} pull requests are already open, which reaches 'open-pull-requests-limit' of {limit}",
+                    candidate.open_pull_requests
+                )
+            };
+            return Ok(Decision::skip(
+                DecisionReason::PullRequestLimit,
+                message,
+                Some("open-pull-requests-limit".into()),
+            ));
+        }
+        Ok(Decision {
+            update: true,
+            reason: DecisionReason::Update,
+            message: format!("The dependency '{name}' would be updated from {current} to {new}"),
+            path: allowed_by,
+        })
+    }
+
+    /// Returns the name of the branch Dependabot would create for a pull
+    /// request that updates `candidate`, e.g., `dependabot/cargo/serde-1.0.200`.
+    ///
+    /// The name consists of `dependabot`, the package manager, the directory,
+    /// the [target branch](Self::target_branch), and the dependency name and
+    /// new version, joined with the [separator](Self::pull_request_branch_name).
+    /// Characters that are not allowed in branch names are removed.
+    #[must_use]
+    pub fn branch_name(&self, candidate: &Candidate) -> String {
+        let name = candidate.name.strip_suffix(".xml").unwrap_or(&candidate.name);
+        let name: String = name
+            .chars()
+            .filter(|&c| c != '@')
+            .map(|c| if matches!(c, ':' | '[' | ']') { '-' } else { c })
+            .collect();
+        let version = &candidate.new_version;
+        // Git commit SHAs are shortened.
+        let version = if version.len() == 40
+            && version.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            &version[..7]
+        } else {
+            version
+        };
+        self.branch_name_with(candidate.directory.as_deref(), &format!("{name}-{version}"))
+    }
+
+    /// Returns the name of the branch Dependabot would create for a pull
+    /// request that updates `candidates` as the [group](Self::groups) named
+    /// `group`, e.g., `dependabot/cargo/rust-dependencies-0a1b2c3d4e`.
+    ///
+    /// The name is the same as [`branch_name`](Self::branch_name), except that
+    /// the last part is the group name followed by a digest of the names and
+    /// new versions of `candidates`. The directory is that of the first
+    /// candidate.
+    #[must_use]
+    pub fn group_branch_name(&self, group: &str, candidates: &[Candidate]) -> String {
+        let mut dependencies: Vec<String> = candidates
+            .iter()
+            .map(|candidate| format!("{}-{}", candidate.name, candidate.new_version))
+            .collect();
+        dependencies.sort_unstable();
+        let digest = md5::hex_digest(dependencies.join(",").as_bytes());
+        let directory = candidates.first().and_then(|candidate| candidate.directory.as_deref());
+        self.branch_name_with(directory, &format!("{group}-{}", &digest[..10]))
+    }
+
+    fn branch_name_with(&self, directory: Option<&str>, last: &str) -> String {
+        let directory = directory.or(self.directory.as_deref()).unwrap_or("/").replace(' ', "-");
+        let mut name = format!("dependabot/{}", self.package_ecosystem.package_manager());
+        for part in
+            [Some(&*directory), self.target_branch.as_deref(), Some(last)].into_iter().flatten()
+        {
+            // Joined like Ruby's `File.join`, which doesn't double slashes.
+            let part = part.trim_matches('/');
+            if !part.is_empty() {
+                name.push('/');
+                name.push_str(part);
+            }
         }
+        let separator = self.pull_request_branch_name.as_ref().map_or('/', |b| b.separator.repr);
+        // As in Dependabot, slashes are replaced after sanitizing.
+        sanitize_branch_name(&name).replace('/', separator.encode_utf8(&mut [0; 4]))
     }
 }
 
-/// Package manager to use.
-///
-/// See [GitHub Docs][docs] for more.
-///
-/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#package-ecosystem-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
-#[serde(rename_all = "kebab-case")]
+/// Removes characters that are not allowed in branch names from `name`, as
+/// Dependabot does.
+fn sanitize_branch_name(name: &str) -> String {
+    let name: String =
+        name.chars().filter(|&c| c.is_ascii_alphanumeric() || "/-_.(){}".contains(c)).collect();
+    // Slashes can't be followed by periods.
+    let name = name.replace("/.", "/dot-");
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '.' | '/') && out.ends_with(c) {
+            continue;
+        }
+        out.push(c);
+    }
+    if out.ends_with('.') {
+        out.pop();
+    }
+    out
+}
+
+/// A dependency update, used by [`Update::evaluate`] and
+/// [`Update::branch_name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub enum PackageEcosystem {
-    /// `bun`
-    Bun,
-    /// `bundler`
-    Bundler,
-    /// `cargo`
-    Cargo,
-    /// `composer`
-    Composer,
-    /// `devcontainers`
-    Devcontainers,
-    /// `docker`
-    Docker,
-    /// `docker-compose`
-    DockerCompose,
-    /// `dotnet-sdk`
-    DotnetSdk,
-    /// `mix` (package manager "Hex")
+pub struct Candidate {
+    /// The name of the dependency.
+    pub name: String,
+    /// Whether the dependency is explicitly defined in a manifest. Defaults
+    /// to `true`.
+    pub direct: bool,
+    /// Whether the dependency is in the development dependency group.
+    /// Defaults to `false`.
+    pub development: bool,
+    /// The version currently in use.
+    pub current_version: String,
+    /// The version to update to.
+    pub new_version: String,
+    /// The version requirement of the dependency in the manifest, e.g.,
+    /// `^1.2`. Used with the `lockfile-only` versioning strategy.
+    pub requirement: Option<String>,
+    /// The number of pull requests for version updates that are already open
+    /// for this update configuration. Defaults to 0.
+    pub open_pull_requests: u32,
+    /// The directory of the manifest that defines the dependency, for
+    /// updates configured with [`Update::directories`]. Defaults to `None`,
+    /// which means [`Update::directory`].
+    pub directory: Option<String>,
+}
+
+impl Candidate {
+    /// Creates a new `Candidate` for a direct production dependency.
+    #[must_use]
+    pub fn new<N: Into<String>, C: Into<String>, V: Into<String>>(
+        name: N,
+        current_version: C,
+        new_version: V,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            direct: true,
+            development: false,
+            current_version: current_version.into(),
+            new_version: new_version.into(),
+            requirement: None,
+            open_pull_requests: 0,
+            directory: None,
+        }
+    }
+}
+
+impl Allow {
+    fn matches(&self, candidate: &Candidate) -> bool {
+        let dependency_type = match &self.dependency_type {
+            None | Some(DependencyType::All) => true,
+            Some(DependencyType::Direct) => candidate.direct,
+            Some(DependencyType::Indirect) => !candidate.direct,
+            Some(DependencyType::Production) => !candidate.development,
+            Some(DependencyType::Development) => candidate.development,
+            #[cfg(feature = "other-variants")]
+            Some(DependencyType::Other(_)) => false,
+        };
+        dependency_type
+            && self.name_pattern().is_none_or(|pattern| pattern.matches(&candidate.name))
+    }
+}
+
+/// The result of [`Update::evaluate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Decision {
+    /// Whether Dependabot would open a pull request for the update.
+    pub update: bool,
+    /// The kind of the rule that decided it.
+    pub reason: DecisionReason,
+    /// A human-readable explanation of the decision.
+    pub message: String,
+    /// The path to the rule that decided it, relative to the [`Update`], e.g.,
+    /// `ignore[1].versions[0]`, or `None` if it is the default behavior.
+    pub path: Option<String>,
+}
+
+impl Decision {
+    fn skip(reason: DecisionReason, message: String, path: Option<String>) -> Self {
+        Self { update: false, reason, message, path }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{path}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// The kind of a [`Decision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum DecisionReason {
+    /// No rule prevents the update.
+    Update,
+    /// The new version is not newer than the current version.
+    NotNewer,
+    /// The dependency doesn't match `allow`.
+    NotAllowed,
+    /// The dependency or the version is ignored by `ignore`.
+    Ignored,
+    /// The new version requires a manifest change, but `versioning-strategy`
+    /// is `lockfile-only`.
+    LockfileOnly,
+    /// `open-pull-requests-limit` is reached.
+    PullRequestLimit,
+}
+
+/// Package manager to use.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#package-ecosystem-
+// Do not implement Copy because of the `Other` variant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum PackageEcosystem {
+    /// `bun`
+    Bun,
+    /// `bundler`
+    Bundler,
+    /// `cargo`
+    Cargo,
+    /// `composer`
+    Composer,
+    /// `devcontainers`
+    Devcontainers,
+    /// `docker`
+    Docker,
+    /// `docker-compose`
+    DockerCompose,
+    /// `dotnet-sdk`
+    DotnetSdk,
+    /// `mix` (package manager "Hex")
     Mix,
     /// `helm`
     Helm,
@@ -272,6 +1604,159 @@ pub enum PackageEcosystem {
     Terraform,
     /// `uv`
     Uv,
+    /// A value not known to this crate, preserved verbatim.
+    ///
+    /// This variant is only available when the `other-variants` feature is
+    /// enabled. Otherwise, unknown values are rejected.
+    #[cfg(feature = "other-variants")]
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A path pattern, used by [`Update::directories`] and [`Update::exclude_paths`].
+///
+/// In addition to literal paths, the following glob syntax is supported:
+///
+/// - `*` matches zero or more characters within a path segment.
+/// - `?` matches exactly one character within a path segment.
+/// - `**` matches zero or more path segments.
+///
+/// Patterns are interpreted relative to the repository root, so leading and
+/// trailing slashes are ignored: `/`, `/apps/*`, and `/**/` are all valid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct PathPattern {
+    repr: String,
+}
+
+impl PathPattern {
+    /// Creates a new `PathPattern`.
+    #[must_use]
+    pub fn new<S: Into<String>>(pattern: S) -> Self {
+        Self { repr: pattern.into() }
+    }
+
+    /// Returns the pattern as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Returns `true` if this pattern contains glob syntax, or `false` if
+    /// it is a literal path.
+    #[must_use]
+    pub fn is_glob(&self) -> bool {
+        crate::glob::is_glob(&self.repr)
+    }
+
+    /// Returns `true` if the given path matches this pattern.
+    ///
+    /// The path is interpreted relative to the repository root.
+    #[must_use]
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(path) = path_segments(path) else { return false };
+        let pattern: Vec<&str> =
+            self.repr.split('/').filter(|s| !s.is_empty() && *s != ".").collect();
+        crate::glob::matches_segments(&pattern, &path)
+    }
+}
+
+impl fmt::Display for PathPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr: String = Deserialize::deserialize(deserializer)?;
+        Ok(Self { repr })
+    }
+}
+
+/// A dependency name pattern, such as [`Allow::dependency_name`] and
+/// [`Ignore::dependency_name`], returned by [`Allow::name_pattern`] and
+/// [`Ignore::name_pattern`].
+///
+/// As in Dependabot, `*` matches zero or more characters (including `/`, so
+/// `@types/*` matches all packages in the `@types` scope), and all other
+/// characters match themselves. Matching is case-insensitive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamePattern {
+    repr: String,
+    /// The lowercased parts of the pattern separated by `*`.
+    parts: Vec<String>,
+}
+
+impl NamePattern {
+    /// Creates a new `NamePattern`.
+    #[must_use]
+    pub fn new<S: Into<String>>(pattern: S) -> Self {
+        let repr = pattern.into();
+        let parts = repr.to_lowercase().split('*').map(String::from).collect();
+        Self { repr, parts }
+    }
+
+    /// Returns the pattern as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Returns `true` if this pattern contains `*`, or `false` if it is a
+    /// literal name.
+    #[must_use]
+    pub fn is_glob(&self) -> bool {
+        self.parts.len() > 1
+    }
+
+    /// Returns `true` if the given dependency name matches this pattern.
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        crate::glob::matches_parts(&self.parts, &name.to_lowercase())
+    }
+}
+
+impl fmt::Display for NamePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+impl Serialize for NamePattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.repr)
+    }
+}
+
+impl<'de> Deserialize<'de> for NamePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr: String = Deserialize::deserialize(deserializer)?;
+        Ok(Self::new(repr))
+    }
+}
+
+/// Splits a path into its normal components, ignoring root and `.`
+/// components. Returns `None` if the path is not valid UTF-8.
+fn path_segments(path: &Path) -> Option<Vec<&str>> {
+    let mut segments = vec![];
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => segments.push(".."),
+            Component::Normal(s) => segments.push(s.to_str()?),
+        }
+    }
+    Some(segments)
 }
 
 /// How often to check for updates.
@@ -279,9 +1764,9 @@ pub enum PackageEcosystem {
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#scheduleinterval
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Schedule {
     /// How often to check for updates.
@@ -294,24 +1779,123 @@ pub struct Schedule {
     /// See [GitHub Docs][docs] for more.
     ///
     /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#scheduletime
-    // TODO: Should we wrap this in its own type and verify the content?
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time: Option<String>,
+    pub time: Option<ScheduleTime>,
     /// Specify an alternative time zone.
     ///
     /// See [GitHub Docs][docs] for more.
     ///
     /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#scheduletimezone
-    // TODO: Should we wrap this in its own type and verify the content?
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timezone: Option<String>,
+    pub timezone: Option<Timezone>,
+    /// Specify a cron expression to check for updates. Used when [`interval`](Self::interval) is [`Interval::Cron`].
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#schedule-
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cronjob: Option<CronExpression>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
 impl Schedule {
     /// Creates a new `Schedule`.
     #[must_use]
     pub fn new(interval: Interval) -> Self {
-        Self { interval, day: None, time: None, timezone: None }
+        Self {
+            interval,
+            day: None,
+            time: None,
+            timezone: None,
+            cronjob: None,
+            extra: IndexMap::new(),
+        }
+    }
+
+    /// Creates a new `Schedule` that checks for updates according to the given
+    /// cron expression.
+    #[must_use]
+    pub fn cron(cronjob: CronExpression) -> Self {
+        Self { cronjob: Some(cronjob), ..Self::new(Interval::Cron) }
+    }
+
+    /// Returns the next `n` times (strictly after `after`) at which this
+    /// schedule checks for updates.
+    ///
+    /// This follows the semantics described in [GitHub Docs][docs]:
+    ///
+    /// - [`Interval::Daily`] runs on every weekday, Monday to Friday.
+    /// - [`Interval::Weekly`] runs on [`day`](Self::day), which defaults to Monday.
+    /// - [`Interval::Monthly`], [`Interval::Quarterly`], [`Interval::Semiannually`],
+    ///   and [`Interval::Yearly`] run on the first day of the month, quarter,
+    ///   half-year, and year, respectively.
+    /// - [`Interval::Cron`] runs at the times matched by [`cronjob`](Self::cronjob).
+    ///   If `cronjob` is not set, this returns an empty list.
+    ///
+    /// Runs happen at [`time`](Self::time) in [`timezone`](Self::timezone).
+    /// GitHub picks a random time if `time` is not set; this method uses 00:00
+    /// in that case. `timezone` defaults to UTC.
+    ///
+    /// Local times are converted to UTC using the current rules of the time
+    /// zone. A local time that is skipped by a daylight saving time transition
+    /// is shifted forward, and a repeated local time resolves to the earlier one.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#schedule-
+    #[must_use]
+    pub fn next_runs(&self, after: SystemTime, n: usize) -> Vec<SystemTime> {
+        // Covers every combination of day of month, month, and day of week.
+        const MAX_DAYS: i64 = 400 * 365 + 97;
+
+        let tz = self.timezone.as_ref().map_or(TzRule::UTC, Timezone::rule);
+        let after = time::to_unix(after);
+        let at = self.time.unwrap_or_default();
+        let mut runs = vec![];
+        let mut last = after;
+        let mut local_times = vec![];
+        let first_day = tz.local_from_utc(after).div_euclid(time::SECS_PER_DAY);
+        for days in first_day..first_day.saturating_add(MAX_DAYS) {
+            if runs.len() >= n {
+                break;
+            }
+            let (_, month, day_of_month) = time::civil_from_days(days);
+            let day_of_week = time::weekday(days);
+            let first_day_of = |months: &[u8]| day_of_month == 1 && months.contains(&month);
+            local_times.clear();
+            match self.interval {
+                Interval::Daily if (1..=5).contains(&day_of_week) => local_times.push(at),
+                Interval::Weekly if day_of_week == self.day.unwrap_or_default().weekday() => {
+                    local_times.push(at);
+                }
+                Interval::Monthly if day_of_month == 1 => local_times.push(at),
+                Interval::Quarterly if first_day_of(&[1, 4, 7, 10]) => local_times.push(at),
+                Interval::Semiannually if first_day_of(&[1, 7]) => local_times.push(at),
+                Interval::Yearly if first_day_of(&[1]) => local_times.push(at),
+                Interval::Cron => {
+                    let Some(cron) = &self.cronjob else { break };
+                    if cron.matches_date(day_of_month, month, day_of_week) {
+                        for hour in cron.hours() {
+                            local_times
+                                .extend(cron.minutes().map(|minute| ScheduleTime { hour, minute }));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            for t in &local_times {
+                let local =
+                    days * time::SECS_PER_DAY + i64::from(t.hour) * 3600 + i64::from(t.minute) * 60;
+                let utc = tz.utc_from_local(local);
+                // Skip duplicates caused by repeated or skipped local times around DST transitions.
+                if utc > last {
+                    last = utc;
+                    runs.extend(time::from_unix(utc));
+                }
+            }
+        }
+        runs.truncate(n);
+        runs
     }
 }
 
@@ -330,6 +1914,371 @@ pub enum Interval {
     Weekly,
     /// Runs once each month. This is on the first day of the month.
     Monthly,
+    /// Runs once each quarter. This is on the first day of January, April, July, and October.
+    Quarterly,
+    /// Runs every six months. This is on the first day of January and July.
+    Semiannually,
+    /// Runs once each year. This is on the first day of January.
+    Yearly,
+    /// Runs according to the cron expression specified by [`Schedule::cronjob`].
+    Cron,
+}
+
+/// Specify an alternative time of day to check for updates.
+///
+/// This is in `hh:mm` format (24-hour clock), e.g., `09:00` or `17:30`.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#scheduletime
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduleTime {
+    hour: u8,
+    minute: u8,
+}
+
+impl ScheduleTime {
+    /// Creates a new `ScheduleTime`.
+    ///
+    /// Returns `None` if `hour` is greater than 23 or `minute` is greater than 59.
+    #[must_use]
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 { Some(Self { hour, minute }) } else { None }
+    }
+
+    /// Returns the hour (0-23).
+    #[must_use]
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute (0-59).
+    #[must_use]
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let (hour, minute) = s.split_once(':')?;
+        if hour.len() != 2 || minute.len() != 2 {
+            return None;
+        }
+        if !hour.bytes().chain(minute.bytes()).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Self::new(hour.parse().ok()?, minute.parse().ok()?)
+    }
+}
+
+impl fmt::Display for ScheduleTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for ScheduleTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| schedule_time_error(s))
+    }
+}
+
+fn schedule_time_error(s: &str) -> Error {
+    Error::property("schedule/time", s, "did not match the regex '^([01][0-9]|2[0-3]):[0-5][0-9]$'")
+}
+
+impl Serialize for ScheduleTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| de::Error::custom(schedule_time_error(&s)))
+    }
+}
+
+/// Specify an alternative time zone.
+///
+/// This is a time zone identifier in the Area/Location form from the [IANA
+/// time zone database][tz], e.g., `Asia/Tokyo` or `America/New_York`, or
+/// `UTC`. As in GitHub, legacy identifiers such as `EST` and `US/Eastern` are
+/// rejected.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#scheduletimezone
+/// [tz]: https://www.iana.org/time-zones
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timezone {
+    name: &'static str,
+    // POSIX TZ string describing the current rule of this time zone.
+    rule: &'static str,
+}
+
+impl Timezone {
+    /// Returns the time zone identifier as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.name
+    }
+
+    fn rule(&self) -> TzRule {
+        // The generated time zone data only contains valid rules.
+        TzRule::parse(self.rule).unwrap()
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let i = crate::tz::TIME_ZONES.binary_search_by_key(&s, |&(name, _)| name).ok()?;
+        let (name, rule) = crate::tz::TIME_ZONES[i];
+        Some(Self { name, rule })
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| timezone_error(s))
+    }
+}
+
+fn timezone_error(s: &str) -> Error {
+    Error::property(
+        "schedule/timezone",
+        s,
+        "did not match any time zone in the IANA time zone database",
+    )
+}
+
+impl Serialize for Timezone {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timezone {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| de::Error::custom(timezone_error(&s)))
+    }
+}
+
+/// A cron expression, used by [`Schedule::cronjob`].
+///
+/// The expression consists of five fields separated by whitespace:
+///
+/// | Field        | Allowed values                                  |
+/// | ------------ | ----------------------------------------------- |
+/// | minute       | 0-59                                            |
+/// | hour         | 0-23                                            |
+/// | day of month | 1-31                                            |
+/// | month        | 1-12 or `jan`-`dec`                             |
+/// | day of week  | 0-7 or `sun`-`sat` (both 0 and 7 mean Sunday)   |
+///
+/// Each field is `*`, a single value, a range (`1-5`), a stepped range
+/// (`*/15`, `1-30/5`, `10/5`), or a comma-separated list of them.
+///
+/// As in standard cron, if both the day of month and the day of week are
+/// restricted (i.e., not `*`), a day matches when either field matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CronExpression {
+    repr: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+const CRON_MONTHS: &[&str] =
+    &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const CRON_DAYS_OF_WEEK: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+impl CronExpression {
+    /// Returns the expression as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Returns an iterator over the minutes (0-59) matched by this expression.
+    pub fn minutes(&self) -> impl Iterator<Item = u8> {
+        cron_bits(self.minutes)
+    }
+
+    /// Returns an iterator over the hours (0-23) matched by this expression.
+    pub fn hours(&self) -> impl Iterator<Item = u8> {
+        cron_bits(self.hours)
+    }
+
+    /// Returns an iterator over the days of month (1-31) matched by this expression.
+    pub fn days_of_month(&self) -> impl Iterator<Item = u8> {
+        cron_bits(self.days_of_month)
+    }
+
+    /// Returns an iterator over the months (1-12) matched by this expression.
+    pub fn months(&self) -> impl Iterator<Item = u8> {
+        cron_bits(self.months)
+    }
+
+    /// Returns an iterator over the days of week (0-6, 0 is Sunday) matched by this expression.
+    pub fn days_of_week(&self) -> impl Iterator<Item = u8> {
+        cron_bits(self.days_of_week)
+    }
+
+    /// Returns `true` if the given date matches the day of month, month, and
+    /// day of week (0-6, 0 is Sunday) fields of this expression.
+    fn matches_date(&self, day_of_month: u8, month: u8, day_of_week: u8) -> bool {
+        let day_of_month_matches = self.days_of_month & (1 << day_of_month) != 0;
+        let day_of_week_matches = self.days_of_week & (1 << day_of_week) != 0;
+        let day_matches = if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month_matches || day_of_week_matches
+        } else {
+            day_of_month_matches && day_of_week_matches
+        };
+        day_matches && self.months & (1 << month) != 0
+    }
+
+    fn parse(s: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let &[minute, hour, day_of_month, month, day_of_week] = &fields[..] else {
+            return Err(format!(
+                "expected 5 fields (minute, hour, day of month, month, day of week), found {}",
+                fields.len()
+            ));
+        };
+        let mut days_of_week =
+            parse_cron_field(day_of_week, "day of week", 0, 7, CRON_DAYS_OF_WEEK)?;
+        // Both 0 and 7 mean Sunday.
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            repr: s.into(),
+            minutes: parse_cron_field(minute, "minute", 0, 59, &[])?,
+            hours: parse_cron_field(hour, "hour", 0, 23, &[])?,
+            days_of_month: parse_cron_field(day_of_month, "day of month", 1, 31, &[])?,
+            months: parse_cron_field(month, "month", 1, 12, CRON_MONTHS)?,
+            days_of_week,
+            day_of_month_restricted: !day_of_month.starts_with('*'),
+            day_of_week_restricted: !day_of_week.starts_with('*'),
+        })
+    }
+}
+
+fn cron_bits(bits: u64) -> impl Iterator<Item = u8> {
+    (0..64).filter(move |i| bits & (1 << i) != 0)
+}
+
+/// Parses a field of a cron expression into a bit set of matched values.
+///
+/// `names` are alternative names for values starting from `min`.
+fn parse_cron_field(
+    field: &str,
+    name: &str,
+    min: u8,
+    max: u8,
+    names: &[&str],
+) -> Result<u64, String> {
+    let value = |s: &str| -> Result<u8, String> {
+        let n = match names.iter().position(|n| n.eq_ignore_ascii_case(s)) {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(i) => min + i as u8,
+            None => s.parse().map_err(|_| format!("invalid {name} value \"{s}\""))?,
+        };
+        if n < min || n > max {
+            return Err(format!("{name} value \"{s}\" is out of range {min}-{max}"));
+        }
+        Ok(n)
+    };
+    let mut bits = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => match step.parse::<u8>() {
+                Ok(step @ 1..) => (range, Some(step)),
+                _ => return Err(format!("invalid {name} step \"{step}\"")),
+            },
+            None => (item, None),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let (start, end) = (value(start)?, value(end)?);
+            if start > end {
+                return Err(format!("invalid {name} range \"{range}\""));
+            }
+            (start, end)
+        } else {
+            let start = value(range)?;
+            // `a/n` is equivalent to `a-max/n`.
+            (start, if step.is_some() { max } else { start })
+        };
+        for n in (start..=end).step_by(step.unwrap_or(1).into()) {
+            bits |= 1 << n;
+        }
+    }
+    Ok(bits)
+}
+
+impl fmt::Display for CronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).map_err(|e| cron_error(s, &e))
+    }
+}
+
+fn cron_error(s: &str, e: &str) -> Error {
+    Error::property("schedule/cronjob", s, &format!("did not match a cron expression: {e}"))
+}
+
+impl Serialize for CronExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.repr)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronExpression {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        Self::parse(&s).map_err(|e| de::Error::custom(cron_error(&s, &e)))
+    }
 }
 
 /// Specify an alternative day to check for updates.
@@ -358,73 +2307,279 @@ pub enum Day {
     Sunday,
 }
 
-/// Customize which updates are allowed.
+impl Day {
+    /// Returns the day of week (0-6, 0 is Sunday).
+    fn weekday(self) -> u8 {
+        match self {
+            Self::Sunday => 0,
+            Self::Monday => 1,
+            Self::Tuesday => 2,
+            Self::Wednesday => 3,
+            Self::Thursday => 4,
+            Self::Friday => 5,
+            Self::Saturday => 6,
+        }
+    }
+}
+
+/// Customize which updates are allowed.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#allow
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct Allow {
+    /// Allow updates for dependencies with matching names, optionally using * to match zero or more characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_name: Option<String>,
+    /// Allow updates for dependencies of specific types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_type: Option<DependencyType>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+impl Allow {
+    /// Returns [`dependency_name`](Self::dependency_name) as a
+    /// [`NamePattern`], or `None` if it is not specified.
+    #[must_use]
+    pub fn name_pattern(&self) -> Option<NamePattern> {
+        self.dependency_name.as_deref().map(NamePattern::new)
+    }
+
+    /// Returns `true` if the given dependency name matches
+    /// [`dependency_name`](Self::dependency_name), or if `dependency_name` is
+    /// not specified.
+    ///
+    /// This parses the pattern on each call; use
+    /// [`name_pattern`](Self::name_pattern) to match many names.
+    #[must_use]
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name_pattern().is_none_or(|pattern| pattern.matches(name))
+    }
+}
+
+/// Allow updates for dependencies of specific types.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#allow
+// Do not implement Copy because of the `Other` variant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum DependencyType {
+    /// All explicitly defined dependencies.
+    Direct,
+    /// Dependencies of direct dependencies (also known as sub-dependencies, or transient dependencies).
+    Indirect,
+    /// All explicitly defined dependencies. For bundler, pip, composer, cargo, also the dependencies of direct dependencies.
+    All,
+    /// Only dependencies in the "Product dependency group".
+    Production,
+    /// Only dependencies in the "Development dependency group".
+    Development,
+    /// A value not known to this crate, preserved verbatim.
+    ///
+    /// This variant is only available when the `other-variants` feature is
+    /// enabled. Otherwise, unknown values are rejected.
+    #[cfg(feature = "other-variants")]
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Commit message preferences.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#commit-message
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct CommitMessage {
+    /// Specify a prefix for all commit messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Specify a separate prefix for all commit messages that update dependencies in the Development dependency group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_development: Option<String>,
+    /// Specify that any prefix is followed by a list of the dependencies updated in the commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<CommitMessageInclude>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+/// Specify that any prefix is followed by a list of the dependencies updated in the commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum CommitMessageInclude {
+    /// Specify that any prefix is followed by a list of the dependencies updated in the commit.
+    Scope,
+}
+
+/// Delay updates for newly released versions.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#cooldown-
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct Cooldown {
+    /// Default cooldown period for dependencies without specific rules (in days).
+    #[serde(
+        default,
+        deserialize_with = "de_default_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_days: Option<u32>,
+    /// Cooldown period for major version updates (in days).
+    #[serde(
+        default,
+        deserialize_with = "de_semver_major_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semver_major_days: Option<u32>,
+    /// Cooldown period for minor version updates (in days).
+    #[serde(
+        default,
+        deserialize_with = "de_semver_minor_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semver_minor_days: Option<u32>,
+    /// Cooldown period for patch version updates (in days).
+    #[serde(
+        default,
+        deserialize_with = "de_semver_patch_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semver_patch_days: Option<u32>,
+    /// Dependencies to apply the cooldown to, optionally using * to match zero or more characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+    /// Dependencies excluded from the cooldown, optionally using * to match zero or more characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+fn de_cooldown_days<'de, D>(deserializer: D, name: &str) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let n: Option<u32> = Deserialize::deserialize(deserializer)?;
+    match n {
+        None | Some(1..=90) => Ok(n),
+        Some(n) => Err(de::Error::custom(Error::property(
+            &format!("cooldown/{name}"),
+            n,
+            "did not match: an integer between 1 and 90",
+        ))),
+    }
+}
+
+pub(crate) fn de_default_days<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_cooldown_days(deserializer, "default-days")
+}
+
+pub(crate) fn de_semver_major_days<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_cooldown_days(deserializer, "semver-major-days")
+}
+
+pub(crate) fn de_semver_minor_days<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_cooldown_days(deserializer, "semver-minor-days")
+}
+
+pub(crate) fn de_semver_patch_days<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_cooldown_days(deserializer, "semver-patch-days")
+}
+
+/// Group updates for multiple dependencies into a single pull request.
 ///
 /// See [GitHub Docs][docs] for more.
 ///
-/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#allow
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#groups--
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
-pub struct Allow {
-    /// Allow updates for dependencies with matching names, optionally using * to match zero or more characters.
+pub struct Group {
+    /// Specify which type of update the group applies to. When undefined, defaults to version updates.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dependency_name: Option<String>,
-    /// Allow updates for dependencies of specific types.
+    pub applies_to: Option<GroupAppliesTo>,
+    /// Limit the group to dependencies of a specific type.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dependency_type: Option<DependencyType>,
+    pub dependency_type: Option<GroupDependencyType>,
+    /// Include dependencies with matching names in the group, optionally using * to match zero or more characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<String>>,
+    /// Exclude dependencies with matching names from the group, optionally using * to match zero or more characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_patterns: Option<Vec<String>>,
+    /// Limit the group to types of updates, such as semver major, minor, or patch updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_types: Option<Vec<GroupUpdateType>>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
-/// Allow updates for dependencies of specific types.
-///
-/// See [GitHub Docs][docs] for more.
-///
-/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#allow
+/// Specify which type of update the group applies to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
-pub enum DependencyType {
-    /// All explicitly defined dependencies.
-    Direct,
-    /// Dependencies of direct dependencies (also known as sub-dependencies, or transient dependencies).
-    Indirect,
-    /// All explicitly defined dependencies. For bundler, pip, composer, cargo, also the dependencies of direct dependencies.
-    All,
-    /// Only dependencies in the "Product dependency group".
-    Production,
-    /// Only dependencies in the "Development dependency group".
-    Development,
+pub enum GroupAppliesTo {
+    /// Version updates.
+    VersionUpdates,
+    /// Security updates.
+    SecurityUpdates,
 }
 
-/// Commit message preferences.
-///
-/// See [GitHub Docs][docs] for more.
-///
-/// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#commit-message
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+/// Limit the group to dependencies of a specific type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
-pub struct CommitMessage {
-    /// Specify a prefix for all commit messages.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub prefix: Option<String>,
-    /// Specify a separate prefix for all commit messages that update dependencies in the Development dependency group.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub prefix_development: Option<String>,
-    /// Specify that any prefix is followed by a list of the dependencies updated in the commit.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<CommitMessageInclude>,
+pub enum GroupDependencyType {
+    /// Only dependencies in the "Development dependency group".
+    Development,
+    /// Only dependencies in the "Product dependency group".
+    Production,
 }
 
-/// Specify that any prefix is followed by a list of the dependencies updated in the commit.
+/// Types of updates to include in the group, such as semver major, minor, or patch updates.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
-pub enum CommitMessageInclude {
-    /// Specify that any prefix is followed by a list of the dependencies updated in the commit.
-    Scope,
+pub enum GroupUpdateType {
+    /// `major`
+    Major,
+    /// `minor`
+    Minor,
+    /// `patch`
+    Patch,
 }
 
 /// Ignore certain dependencies or versions.
@@ -432,9 +2587,9 @@ pub enum CommitMessageInclude {
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#ignore
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Ignore {
     /// Ignore updates for dependencies with matching names, optionally using * to match zero or more characters.
@@ -443,14 +2598,212 @@ pub struct Ignore {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub versions: Option<Vec<String>>,
     /// Ignore types of updates, such as semver major, minor, or patch updates on version updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub update_types: Option<Vec<UpdateType>>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
 impl Ignore {
     /// Creates a new `Ignore`.
     #[must_use]
     pub fn new(dependency_name: String) -> Self {
-        Self { dependency_name, versions: None, update_types: None }
+        Self { dependency_name, versions: None, update_types: None, extra: IndexMap::new() }
+    }
+
+    /// Returns [`dependency_name`](Self::dependency_name) as a
+    /// [`NamePattern`].
+    #[must_use]
+    pub fn name_pattern(&self) -> NamePattern {
+        NamePattern::new(&*self.dependency_name)
+    }
+
+    /// Returns `true` if the given dependency name matches
+    /// [`dependency_name`](Self::dependency_name).
+    ///
+    /// This parses the pattern on each call; use
+    /// [`name_pattern`](Self::name_pattern) to match many names.
+    #[must_use]
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name_pattern().matches(name)
+    }
+
+    /// Returns `true` if the given version of the dependency is ignored by
+    /// this rule, interpreting [`versions`](Self::versions) with the syntax of
+    /// `ecosystem` (see [`VersionRequirement`]).
+    ///
+    /// As in Dependabot, a rule without `versions` and `update-types` ignores
+    /// all versions. [`update_types`](Self::update_types) depends on the
+    /// current version of the dependency and is not considered here; a rule
+    /// with only `update-types` ignores no version by this method.
+    ///
+    /// This doesn't check the dependency name; see [`matches_name`](Self::matches_name).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `version` or any of `versions` cannot be parsed.
+    pub fn is_ignored(&self, ecosystem: &PackageEcosystem, version: &str) -> Result<bool, Error> {
+        let version = Version::parse(ecosystem, version)?;
+        let Some(versions) = &self.versions else { return Ok(self.update_types.is_none()) };
+        for requirement in versions {
+            if VersionRequirement::parse(ecosystem, requirement)?.matches(&version) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl PackageEcosystem {
+    /// Returns the name of the package manager in Dependabot's branch names.
+    pub(crate) fn package_manager(&self) -> String {
+        match self {
+            Self::Mix => "hex".into(),
+            Self::Gitsubmodule => "submodules".into(),
+            Self::GithubActions => "github_actions".into(),
+            Self::Gomod => "go_modules".into(),
+            Self::Npm => "npm_and_yarn".into(),
+            _ => self.to_string().replace('-', "_"),
+        }
+    }
+
+    fn version_scheme(&self) -> version::Scheme {
+        match self {
+            Self::Cargo => version::Scheme::Cargo,
+            Self::Bundler => version::Scheme::Ruby,
+            Self::Pip | Self::Uv => version::Scheme::Pep440,
+            Self::Gradle | Self::Maven => version::Scheme::Maven,
+            _ => version::Scheme::Semver,
+        }
+    }
+}
+
+/// A version of a dependency, parsed with the version syntax of a package
+/// ecosystem.
+///
+/// Versions are compared as the package manager of the ecosystem does: for
+/// example, `1.0` equals `1.0.0`, and pre-releases (`1.0.0-rc.1` in SemVer,
+/// `1.0.0.rc1` in RubyGems, `1.0rc1` in PEP 440, and `1.0-RC1` in Maven)
+/// precede the release.
+#[derive(Debug, Clone)]
+pub struct Version {
+    repr: String,
+    parsed: version::Version,
+}
+
+impl Version {
+    /// Parses a version of a dependency in `ecosystem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid version.
+    pub fn parse(ecosystem: &PackageEcosystem, s: &str) -> Result<Self, Error> {
+        let parsed = version::Version::parse(ecosystem.version_scheme(), s)
+            .map_err(|e| Error::parse(Class::Semantic, e))?;
+        Ok(Self { repr: s.into(), parsed })
+    }
+
+    /// Returns the version as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Returns `true` if this is a pre-release version.
+    #[must_use]
+    pub fn is_prerelease(&self) -> bool {
+        self.parsed.is_prerelease()
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.parsed == other.parsed
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.parsed.cmp(&other.parsed)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+/// A version requirement, used by [`Ignore::versions`], parsed with the
+/// syntax of a package ecosystem.
+///
+/// The following syntax is supported in all ecosystems:
+///
+/// - Comparisons: `=1.2.3`, `==1.2.3`, `!=1.2.3`, `>1.2.3`, `>=1.2.3`,
+///   `<1.2.3`, and `<=1.2.3`.
+/// - Wildcards: `*`, `1.x`, and `1.2.*`.
+/// - Compatible releases: `^1.2` (`>=1.2.0, <2.0.0`), `~1.2` (`>=1.2.0,
+///   <1.3.0`), and `~> 1.2` or `~= 1.2` (`>=1.2, <2.0`).
+/// - Multiple requirements that must all be satisfied, separated by commas
+///   or whitespace: `>= 1.0, < 2.0`.
+/// - Alternatives separated by `||` (or `|`): `^1.0 || ^2.0`.
+/// - Hyphen ranges: `1.0 - 2.0`.
+///
+/// The meaning of a bare version depends on the ecosystem: it is a
+/// compatible release (`^1.2`) in `cargo`, an exact version in `bundler`,
+/// `pip`, `uv`, `maven`, and `gradle`, and otherwise an exact version whose
+/// missing parts are wildcards (`1.2` means `1.2.x`), as in npm.
+///
+/// In `maven` and `gradle`, Maven version ranges are also supported:
+/// `[1.0,2.0)`, `(,1.0],[1.2,)`, and `[1.5]`.
+///
+/// As in Dependabot, the upper bound of a compatible release or a wildcard
+/// excludes its pre-releases (`^1.2` doesn't match `2.0.0-alpha`), but
+/// pre-releases are otherwise matched like other versions.
+#[derive(Debug, Clone)]
+pub struct VersionRequirement {
+    repr: String,
+    parsed: version::Requirement,
+}
+
+impl VersionRequirement {
+    /// Parses a version requirement for dependencies in `ecosystem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid version requirement.
+    pub fn parse(ecosystem: &PackageEcosystem, s: &str) -> Result<Self, Error> {
+        let parsed = version::Requirement::parse(ecosystem.version_scheme(), s).map_err(|e| {
+            Error::parse(Class::Semantic, format!("invalid version requirement \"{s}\": {e}"))
+        })?;
+        Ok(Self { repr: s.into(), parsed })
+    }
+
+    /// Returns the requirement as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Returns `true` if the given version satisfies this requirement.
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.parsed.matches(&version.parsed)
+    }
+}
+
+impl fmt::Display for VersionRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.repr)
     }
 }
 
@@ -471,6 +2824,60 @@ pub enum UpdateType {
     SemverPatch,
 }
 
+impl UpdateType {
+    /// Classifies the update of a dependency in `ecosystem` from version `old`
+    /// to version `new`, or returns `None` if the versions are equal.
+    ///
+    /// The update type is determined by the first release number that
+    /// changed: the first is major, the second is minor, and the rest
+    /// (including the fourth number of NuGet versions such as `1.2.3.4`) are
+    /// patch. A change of only the pre-release part (e.g., `1.0.0-rc.1` to
+    /// `1.0.0`) is a patch update. In addition:
+    ///
+    /// - In `cargo`, the leftmost non-zero number of `old` is major, as in
+    ///   Cargo's compatibility rules: `0.2.3` to `0.3.0` is a major update,
+    ///   and `0.2.3` to `0.2.4` is a minor update.
+    /// - In `pip` and `uv`, a change of the epoch (e.g., `1.0` to `1!0.5`) is a
+    ///   major update.
+    /// - Calendar versions are classified like any other version, as in
+    ///   Dependabot: `2023.12.1` to `2024.1.0` is a major update, and
+    ///   `2024.1.0` to `2024.2.0` is a minor update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `old` or `new` is not a valid version.
+    pub fn classify(
+        ecosystem: &PackageEcosystem,
+        old: &str,
+        new: &str,
+    ) -> Result<Option<Self>, Error> {
+        let old = Version::parse(ecosystem, old)?;
+        let new = Version::parse(ecosystem, new)?;
+        Ok(Self::between(ecosystem, &old, &new))
+    }
+
+    fn between(ecosystem: &PackageEcosystem, old: &Version, new: &Version) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        let Some(i) = old.parsed.changed_index(&new.parsed) else {
+            return Some(Self::SemverPatch);
+        };
+        let old_release = old.parsed.release();
+        let major = if *ecosystem == PackageEcosystem::Cargo {
+            // All numbers of `0.0.0` are major.
+            old_release.iter().position(|&n| n != 0).unwrap_or(old_release.len() - 1)
+        } else {
+            0
+        };
+        Some(match i {
+            _ if i <= major => Self::SemverMajor,
+            _ if i == major + 1 => Self::SemverMinor,
+            _ => Self::SemverPatch,
+        })
+    }
+}
+
 /// Allow or deny code execution in manifest files.
 ///
 /// See [GitHub Docs][docs] for more.
@@ -491,13 +2898,16 @@ pub enum InsecureExternalCodeExecution {
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#pull-request-branch-nameseparator
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct PullRequestBranchName {
     /// Change separator for pull request branch names.
     pub separator: Separator,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
 }
 
 /// Change separator for pull request branch names.
@@ -526,9 +2936,10 @@ impl<'de> Deserialize<'de> for Separator {
         let c: char = Deserialize::deserialize(deserializer)?;
         match c {
             '-' | '/' | '_' => Ok(Self { repr: c }),
-            _ => Err(de::Error::custom(format!(
-                "The property 'pull-request-branch-name/separator' value \"{c}\" \
-                     did not match one of the following values: -, /, _",
+            _ => Err(de::Error::custom(Error::property(
+                "pull-request-branch-name/separator",
+                c,
+                "did not match one of the following values: -, /, _",
             ))),
         }
     }
@@ -549,12 +2960,81 @@ pub enum RebaseStrategy {
     Auto,
 }
 
+/// Private registries that Dependabot can access when updating dependencies.
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference#registries--
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UpdateRegistries {
+    /// All registries defined in [`Dependabot::registries`] (`"*"`).
+    All,
+    /// The registries with the given names.
+    Names(Vec<String>),
+}
+
+impl Serialize for UpdateRegistries {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::All => serializer.serialize_str("*"),
+            Self::Names(names) => serializer.collect_seq(names),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateRegistries {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UpdateRegistries;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("\"*\" or a list of registry names")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match v {
+                    "*" => Ok(UpdateRegistries::All),
+                    _ => {
+                        Err(de::Error::custom(Error::property("registries", v, "did not match: *")))
+                    }
+                }
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut names = vec![];
+                while let Some(name) = seq.next_element()? {
+                    names.push(name);
+                }
+                Ok(UpdateRegistries::Names(names))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
 /// How to update manifest version requirements.
 ///
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#versioning-strategy
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+// Do not implement Copy because of the `Other` variant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
 pub enum VersioningStrategy {
@@ -568,6 +3048,13 @@ pub enum VersioningStrategy {
     LockfileOnly,
     /// Relax the version requirement to include both the new and old version, when possible.
     Widen,
+    /// A value not known to this crate, preserved verbatim.
+    ///
+    /// This variant is only available when the `other-variants` feature is
+    /// enabled. Otherwise, unknown values are rejected.
+    #[cfg(feature = "other-variants")]
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Configuration options for private registries.
@@ -582,9 +3069,9 @@ pub type Registries = IndexMap<String, Registry>;
 /// See [GitHub Docs][docs] for more.
 ///
 /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#configuration-options-for-private-registries
-#[derive(Debug, Clone, Deserialize, Serialize)]
+// `Deserialize` is generated in src/gen/extra.rs from the serde attributes below.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Registry {
     /// Identifies the type of registry.
@@ -608,13 +3095,70 @@ pub struct Registry {
     /// For registries, if the boolean value is true, Dependabot will resolve dependencies by using the specified URL rather than the base URL of that specific ecosystem.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub replaces_base: Option<bool>,
+    /// The name of the Hex organization. Used by [`RegistryType::HexOrganization`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    /// The name of the Hex repository. Used by [`RegistryType::HexRepository`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// A reference to a Dependabot secret containing the authentication key for the Hex repository. Used by [`RegistryType::HexRepository`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_key: Option<String>,
+    /// The fingerprint of the public key for the Hex repository. Used by [`RegistryType::HexRepository`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_fingerprint: Option<String>,
+    /// The name of the registry as configured in `.cargo/config.toml`. Used by [`RegistryType::CargoRegistry`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// Properties not known to this crate. See [`ParseMode`] for more.
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+impl Registry {
+    /// Creates a new `Registry` of the given type with no properties set.
+    #[must_use]
+    pub fn new(type_: RegistryType) -> Self {
+        Self {
+            type_,
+            url: None,
+            username: None,
+            password: None,
+            key: None,
+            token: None,
+            replaces_base: None,
+            organization: None,
+            repo: None,
+            auth_key: None,
+            public_key_fingerprint: None,
+            registry: None,
+            extra: IndexMap::new(),
+        }
+    }
+
+    fn set_credentials(&mut self, credentials: RegistryCredentials) {
+        match credentials {
+            RegistryCredentials::UsernamePassword { username, password } => {
+                self.username = Some(username);
+                self.password = Some(password);
+            }
+            RegistryCredentials::Token { token } => self.token = Some(token),
+        }
+    }
 }
 
 /// Identifies the type of registry.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+// Do not implement Copy because of the `Other` variant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
 pub enum RegistryType {
+    /// The `cargo-registry` type.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/configuring-access-to-private-registries-for-dependabot#cargo-registry
+    CargoRegistry,
     /// The `composer-repository` type.
     ///
     /// See [GitHub Docs][docs] for more.
@@ -633,19 +3177,30 @@ pub enum RegistryType {
     ///
     /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#git
     Git,
+    /// The `goproxy-server` type.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/configuring-access-to-private-registries-for-dependabot#goproxy-server
+    GoproxyServer,
+    /// The `helm-registry` type.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/configuring-access-to-private-registries-for-dependabot#helm-registry
+    HelmRegistry,
     /// The `hex-organization` type.
     ///
     /// See [GitHub Docs][docs] for more.
     ///
     /// [docs]: https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/configuration-options-for-the-dependabot.yml-file#hex-organization
     HexOrganization,
-    // TODO: this type has some additional fields.
-    // /// The `hex-repository` type.
-    // ///
-    // /// See [GitHub Docs][docs] for more.
-    // ///
-    // /// [docs]: https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/configuration-options-for-the-dependabot.yml-file#hex-repository
-    // HexRepository,
+    /// The `hex-repository` type.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/configuration-options-for-the-dependabot.yml-file#hex-repository
+    HexRepository,
     /// The `maven-repository` type.
     ///
     /// See [GitHub Docs][docs] for more.
@@ -664,6 +3219,12 @@ pub enum RegistryType {
     ///
     /// [docs]: https://docs.github.com/en/code-security/supply-chain-security/configuration-options-for-dependency-updates#nuget-feed
     NugetFeed,
+    /// The `pub-repository` type.
+    ///
+    /// See [GitHub Docs][docs] for more.
+    ///
+    /// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/configuring-access-to-private-registries-for-dependabot#pub-repository
+    PubRepository,
     /// The `python-index` type.
     ///
     /// See [GitHub Docs][docs] for more.
@@ -682,4 +3243,416 @@ pub enum RegistryType {
     ///
     /// [docs]: https://docs.github.com/en/code-security/dependabot/dependabot-version-updates/configuration-options-for-the-dependabot.yml-file#terraform-registry
     TerraformRegistry,
+    /// A value not known to this crate, preserved verbatim.
+    ///
+    /// This variant is only available when the `other-variants` feature is
+    /// enabled. Otherwise, unknown values are rejected.
+    #[cfg(feature = "other-variants")]
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A type-safe representation of [`Registry`].
+///
+/// Unlike [`Registry`], each variant only has the properties that are valid for
+/// the type of registry, and required properties are not optional.
+///
+/// This can be converted to and from [`Registry`] using [`TryFrom`] and [`From`].
+///
+/// See [GitHub Docs][docs] for more.
+///
+/// [docs]: https://docs.github.com/en/code-security/dependabot/working-with-dependabot/configuring-access-to-private-registries-for-dependabot
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "Registry", into = "Registry")]
+#[non_exhaustive]
+pub enum TypedRegistry {
+    /// The `cargo-registry` type.
+    CargoRegistry {
+        /// The name of the registry as configured in `.cargo/config.toml`.
+        registry: String,
+        /// The URL of the registry.
+        url: String,
+        /// A reference to a Dependabot secret containing an access token for this registry.
+        token: String,
+    },
+    /// The `composer-repository` type.
+    ComposerRepository {
+        /// The URL of the repository.
+        url: String,
+        /// The username that Dependabot uses to access the repository.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+    },
+    /// The `docker-registry` type.
+    DockerRegistry {
+        /// The URL of the registry.
+        url: String,
+        /// The username that Dependabot uses to access the registry.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+        /// Whether Dependabot resolves dependencies by using this registry rather than the default one.
+        replaces_base: Option<bool>,
+    },
+    /// The `git` type.
+    Git {
+        /// The URL of the Git server.
+        url: String,
+        /// The username that Dependabot uses to access the Git server.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+    },
+    /// The `goproxy-server` type.
+    GoproxyServer {
+        /// The URL of the Go proxy server.
+        url: String,
+        /// The username that Dependabot uses to access the server.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+    },
+    /// The `helm-registry` type.
+    HelmRegistry {
+        /// The URL of the registry.
+        url: String,
+        /// The username that Dependabot uses to access the registry.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+    },
+    /// The `hex-organization` type.
+    HexOrganization {
+        /// The name of the Hex organization.
+        organization: String,
+        /// A reference to a Dependabot secret containing an access key for the organization.
+        key: String,
+    },
+    /// The `hex-repository` type.
+    HexRepository {
+        /// The name of the Hex repository.
+        repo: String,
+        /// The URL of the repository.
+        url: String,
+        /// A reference to a Dependabot secret containing the authentication key for the repository.
+        auth_key: String,
+        /// The fingerprint of the public key for the repository.
+        public_key_fingerprint: Option<String>,
+    },
+    /// The `maven-repository` type.
+    MavenRepository {
+        /// The URL of the repository.
+        url: String,
+        /// The credentials that Dependabot uses to access the repository.
+        credentials: RegistryCredentials,
+        /// Whether Dependabot resolves dependencies by using this repository rather than the default one.
+        replaces_base: Option<bool>,
+    },
+    /// The `npm-registry` type.
+    NpmRegistry {
+        /// The URL of the registry.
+        url: String,
+        /// The credentials that Dependabot uses to access the registry.
+        credentials: RegistryCredentials,
+        /// Whether Dependabot resolves dependencies by using this registry rather than the default one.
+        replaces_base: Option<bool>,
+    },
+    /// The `nuget-feed` type.
+    NugetFeed {
+        /// The URL of the feed.
+        url: String,
+        /// The credentials that Dependabot uses to access the feed.
+        credentials: RegistryCredentials,
+    },
+    /// The `pub-repository` type.
+    PubRepository {
+        /// The URL of the repository.
+        url: String,
+        /// A reference to a Dependabot secret containing an access token for this repository.
+        token: String,
+    },
+    /// The `python-index` type.
+    PythonIndex {
+        /// The URL of the index.
+        url: String,
+        /// The credentials that Dependabot uses to access the index.
+        credentials: RegistryCredentials,
+        /// Whether Dependabot resolves dependencies by using this index rather than the default one.
+        replaces_base: Option<bool>,
+    },
+    /// The `rubygems-server` type.
+    RubygemsServer {
+        /// The URL of the server.
+        url: String,
+        /// The credentials that Dependabot uses to access the server.
+        credentials: RegistryCredentials,
+        /// Whether Dependabot resolves dependencies by using this server rather than the default one.
+        replaces_base: Option<bool>,
+    },
+    /// The `terraform-registry` type.
+    TerraformRegistry {
+        /// The URL of the registry.
+        url: String,
+        /// A reference to a Dependabot secret containing an access token for this registry.
+        token: String,
+    },
+}
+
+impl TypedRegistry {
+    /// Returns the type of this registry.
+    #[must_use]
+    pub fn type_(&self) -> RegistryType {
+        match self {
+            Self::CargoRegistry { .. } => RegistryType::CargoRegistry,
+            Self::ComposerRepository { .. } => RegistryType::ComposerRepository,
+            Self::DockerRegistry { .. } => RegistryType::DockerRegistry,
+            Self::Git { .. } => RegistryType::Git,
+            Self::GoproxyServer { .. } => RegistryType::GoproxyServer,
+            Self::HelmRegistry { .. } => RegistryType::HelmRegistry,
+            Self::HexOrganization { .. } => RegistryType::HexOrganization,
+            Self::HexRepository { .. } => RegistryType::HexRepository,
+            Self::MavenRepository { .. } => RegistryType::MavenRepository,
+            Self::NpmRegistry { .. } => RegistryType::NpmRegistry,
+            Self::NugetFeed { .. } => RegistryType::NugetFeed,
+            Self::PubRepository { .. } => RegistryType::PubRepository,
+            Self::PythonIndex { .. } => RegistryType::PythonIndex,
+            Self::RubygemsServer { .. } => RegistryType::RubygemsServer,
+            Self::TerraformRegistry { .. } => RegistryType::TerraformRegistry,
+        }
+    }
+}
+
+/// The credentials that Dependabot uses to access a registry that accepts
+/// either a username and password or a token.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum RegistryCredentials {
+    /// A username and a reference to a Dependabot secret containing the password for the user.
+    UsernamePassword {
+        /// The username that Dependabot uses to access the registry.
+        username: String,
+        /// A reference to a Dependabot secret containing the password for the specified user.
+        password: String,
+    },
+    /// A reference to a Dependabot secret containing an access token.
+    Token {
+        /// A reference to a Dependabot secret containing an access token for this registry.
+        token: String,
+    },
+}
+
+impl TryFrom<Registry> for TypedRegistry {
+    type Error = Error;
+
+    fn try_from(registry: Registry) -> Result<Self, Self::Error> {
+        let mut r = RegistryFields(registry);
+        let typed = match r.0.type_.clone() {
+            RegistryType::CargoRegistry => Self::CargoRegistry {
+                registry: r.required("registry")?,
+                url: r.required("url")?,
+                token: r.required("token")?,
+            },
+            RegistryType::ComposerRepository => Self::ComposerRepository {
+                url: r.required("url")?,
+                username: r.required("username")?,
+                password: r.required("password")?,
+            },
+            RegistryType::DockerRegistry => Self::DockerRegistry {
+                url: r.required("url")?,
+                username: r.required("username")?,
+                password: r.required("password")?,
+                replaces_base: r.0.replaces_base.take(),
+            },
+            RegistryType::Git => Self::Git {
+                url: r.required("url")?,
+                username: r.required("username")?,
+                password: r.required("password")?,
+            },
+            RegistryType::GoproxyServer => Self::GoproxyServer {
+                url: r.required("url")?,
+                username: r.required("username")?,
+                password: r.required("password")?,
+            },
+            RegistryType::HelmRegistry => Self::HelmRegistry {
+                url: r.required("url")?,
+                username: r.required("username")?,
+                password: r.required("password")?,
+            },
+            RegistryType::HexOrganization => Self::HexOrganization {
+                organization: r.required("organization")?,
+                key: r.required("key")?,
+            },
+            RegistryType::HexRepository => Self::HexRepository {
+                repo: r.required("repo")?,
+                url: r.required("url")?,
+                auth_key: r.required("auth-key")?,
+                public_key_fingerprint: r.0.public_key_fingerprint.take(),
+            },
+            RegistryType::MavenRepository => Self::MavenRepository {
+                url: r.required("url")?,
+                credentials: r.credentials()?,
+                replaces_base: r.0.replaces_base.take(),
+            },
+            RegistryType::NpmRegistry => Self::NpmRegistry {
+                url: r.required("url")?,
+                credentials: r.credentials()?,
+                replaces_base: r.0.replaces_base.take(),
+            },
+            RegistryType::NugetFeed => {
+                Self::NugetFeed { url: r.required("url")?, credentials: r.credentials()? }
+            }
+            RegistryType::PubRepository => {
+                Self::PubRepository { url: r.required("url")?, token: r.required("token")? }
+            }
+            RegistryType::PythonIndex => Self::PythonIndex {
+                url: r.required("url")?,
+                credentials: r.credentials()?,
+                replaces_base: r.0.replaces_base.take(),
+            },
+            RegistryType::RubygemsServer => Self::RubygemsServer {
+                url: r.required("url")?,
+                credentials: r.credentials()?,
+                replaces_base: r.0.replaces_base.take(),
+            },
+            RegistryType::TerraformRegistry => {
+                Self::TerraformRegistry { url: r.required("url")?, token: r.required("token")? }
+            }
+            #[cfg(feature = "other-variants")]
+            RegistryType::Other(_) => return Err(r.error("is not supported")),
+        };
+        r.finish()?;
+        Ok(typed)
+    }
+}
+
+impl From<TypedRegistry> for Registry {
+    fn from(registry: TypedRegistry) -> Self {
+        let mut r = Self::new(registry.type_());
+        match registry {
+            TypedRegistry::CargoRegistry { registry, url, token } => {
+                r.registry = Some(registry);
+                r.url = Some(url);
+                r.token = Some(token);
+            }
+            TypedRegistry::ComposerRepository { url, username, password }
+            | TypedRegistry::Git { url, username, password }
+            | TypedRegistry::GoproxyServer { url, username, password }
+            | TypedRegistry::HelmRegistry { url, username, password } => {
+                r.url = Some(url);
+                r.username = Some(username);
+                r.password = Some(password);
+            }
+            TypedRegistry::DockerRegistry { url, username, password, replaces_base } => {
+                r.url = Some(url);
+                r.username = Some(username);
+                r.password = Some(password);
+                r.replaces_base = replaces_base;
+            }
+            TypedRegistry::HexOrganization { organization, key } => {
+                r.organization = Some(organization);
+                r.key = Some(key);
+            }
+            TypedRegistry::HexRepository { repo, url, auth_key, public_key_fingerprint } => {
+                r.repo = Some(repo);
+                r.url = Some(url);
+                r.auth_key = Some(auth_key);
+                r.public_key_fingerprint = public_key_fingerprint;
+            }
+            TypedRegistry::MavenRepository { url, credentials, replaces_base }
+            | TypedRegistry::NpmRegistry { url, credentials, replaces_base }
+            | TypedRegistry::PythonIndex { url, credentials, replaces_base }
+            | TypedRegistry::RubygemsServer { url, credentials, replaces_base } => {
+                r.url = Some(url);
+                r.set_credentials(credentials);
+                r.replaces_base = replaces_base;
+            }
+            TypedRegistry::NugetFeed { url, credentials } => {
+                r.url = Some(url);
+                r.set_credentials(credentials);
+            }
+            TypedRegistry::PubRepository { url, token }
+            | TypedRegistry::TerraformRegistry { url, token } => {
+                r.url = Some(url);
+                r.token = Some(token);
+            }
+        }
+        r
+    }
+}
+
+/// Helper for converting [`Registry`] to [`TypedRegistry`]: properties are taken
+/// out as they are used, and any property left over is reported as an error.
+struct RegistryFields(Registry);
+
+impl RegistryFields {
+    const STRING_FIELDS: &'static [&'static str] = &[
+        "url",
+        "username",
+        "password",
+        "key",
+        "token",
+        "organization",
+        "repo",
+        "auth-key",
+        "public-key-fingerprint",
+        "registry",
+    ];
+
+    fn field(&mut self, name: &str) -> &mut Option<String> {
+        match name {
+            "url" => &mut self.0.url,
+            "username" => &mut self.0.username,
+            "password" => &mut self.0.password,
+            "key" => &mut self.0.key,
+            "token" => &mut self.0.token,
+            "organization" => &mut self.0.organization,
+            "repo" => &mut self.0.repo,
+            "auth-key" => &mut self.0.auth_key,
+            "public-key-fingerprint" => &mut self.0.public_key_fingerprint,
+            "registry" => &mut self.0.registry,
+            _ => unreachable!("{name}"),
+        }
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        Error::parse(Class::Semantic, format!("registry of type '{}' {msg}", self.0.type_))
+    }
+
+    fn required(&mut self, name: &str) -> Result<String, Error> {
+        self.field(name)
+            .take()
+            .ok_or_else(|| self.error(&format!("requires the '{name}' property")))
+    }
+
+    fn credentials(&mut self) -> Result<RegistryCredentials, Error> {
+        match (self.0.username.take(), self.0.password.take(), self.0.token.take()) {
+            (Some(username), Some(password), None) => {
+                Ok(RegistryCredentials::UsernamePassword { username, password })
+            }
+            (None, None, Some(token)) => Ok(RegistryCredentials::Token { token }),
+            (_, _, Some(_)) => {
+                Err(self.error("cannot have both the 'token' property and the 'username'/'password' properties"))
+            }
+            _ => Err(self.error(
+                "requires either both the 'username' and 'password' properties or the 'token' property",
+            )),
+        }
+    }
+
+    fn finish(mut self) -> Result<(), Error> {
+        for &name in Self::STRING_FIELDS {
+            if self.field(name).is_some() {
+                return Err(self.error(&format!("does not support the '{name}' property")));
+            }
+        }
+        if self.0.replaces_base.is_some() {
+            return Err(self.error("does not support the 'replaces-base' property"));
+        }
+        if let Some(name) = self.0.extra.keys().next() {
+            return Err(self.error(&format!("does not support the '{name}' property")));
+        }
+        Ok(())
+    }
 }
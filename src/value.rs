@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use indexmap::IndexMap;
+use serde::{
+    de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess as _},
+    ser::{Serialize, SerializeMap as _, Serializer},
+};
+
+/// A YAML value of a property that is not known to this crate.
+///
+/// See [`v2::ParseMode`](crate::v2::ParseMode) for more.
+///
+/// Floating-point numbers are compared by their bit patterns (e.g., NaN is
+/// equal to NaN), so that values can be used as mapping keys.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Value {
+    /// `null`
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer.
+    Integer(i128),
+    /// A floating-point number.
+    Float(f64),
+    /// A string.
+    String(String),
+    /// A sequence.
+    Sequence(Vec<Value>),
+    /// A mapping. Keys are not necessarily strings (e.g., `{1: a}`).
+    Mapping(IndexMap<Value, Value>),
+    /// A value with a local tag (e.g., `!Ref foo`). The tag doesn't include
+    /// the leading `!`.
+    Tagged(String, Box<Value>),
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Sequence(a), Self::Sequence(b)) => a == b,
+            (Self::Mapping(a), Self::Mapping(b)) => a == b,
+            (Self::Tagged(a, a_value), Self::Tagged(b, b_value)) => a == b && a_value == b_value,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Self::Null => {}
+            Self::Bool(b) => b.hash(state),
+            Self::Integer(n) => n.hash(state),
+            Self::Float(n) => n.to_bits().hash(state),
+            Self::String(s) => s.hash(state),
+            Self::Sequence(seq) => seq.hash(state),
+            // Mappings are equal regardless of the order of their entries.
+            Self::Mapping(map) => map.len().hash(state),
+            Self::Tagged(tag, value) => {
+                tag.hash(state);
+                value.hash(state);
+            }
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(b) => serializer.serialize_bool(*b),
+            Self::Integer(n) => serializer.serialize_i128(*n),
+            Self::Float(n) => serializer.serialize_f64(*n),
+            Self::String(s) => serializer.serialize_str(s),
+            Self::Sequence(seq) => seq.serialize(serializer),
+            Self::Mapping(map) => map.serialize(serializer),
+            // serde_yaml serializes a mapping with a single entry whose key is
+            // a `!`-prefixed string written by `collect_str` as a tagged value.
+            Self::Tagged(tag, value) => {
+                struct Tag<'a>(&'a str);
+
+                impl Serialize for Tag<'_> {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        serializer.collect_str(&format_args!("!{}", self.0))
+                    }
+                }
+
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&Tag(tag), value)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("any YAML value")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Value::Null)
+            }
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(Value::Bool(v))
+            }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Value::Integer(v.into()))
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Value::Integer(v.into()))
+            }
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(Value::Float(v))
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Value::String(v.into()))
+            }
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Value::String(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::new();
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(Value::Sequence(values))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut values = IndexMap::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    values.insert(key, value);
+                }
+                Ok(Value::Mapping(values))
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                // serde_yaml passes values with a local tag as enums.
+                let (tag, value) = data.variant::<String>()?;
+                Ok(Value::Tagged(tag, Box::new(value.newtype_variant()?)))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
@@ -0,0 +1,534 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Parsing and comparison of versions and version requirements, following the
+// syntax of each ecosystem's package manager closely enough to evaluate
+// `ignore` rules the way Dependabot does.
+//
+// All versions are normalized to an epoch, a list of release numbers, and a
+// stage (pre-release, release, or post-release), and compared in that order.
+// Missing release numbers are treated as zero, so `1.2` equals `1.2.0`.
+
+use alloc::{
+    format,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+/// The version syntax of an ecosystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Scheme {
+    /// SemVer, where a bare version means a caret requirement (`1.2` means
+    /// `^1.2`).
+    Cargo,
+    /// SemVer, where a bare version means an exact (or, if partial, wildcard)
+    /// requirement. Used by npm and most other ecosystems.
+    Semver,
+    /// RubyGems versions, e.g., `1.2.3.pre1`.
+    Ruby,
+    /// PEP 440 versions, e.g., `1.2.3rc1` and `1.2.3.post1`.
+    Pep440,
+    /// Maven versions, e.g., `1.2.3-SNAPSHOT`, and version ranges, e.g.,
+    /// `[1.0,2.0)`.
+    Maven,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Ident {
+    Num(u64),
+    Str(String),
+}
+
+impl Ident {
+    fn new(s: &str) -> Self {
+        s.parse().map_or_else(|_| Self::Str(s.to_string()), Self::Num)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Stage {
+    /// A pre-release. An empty list is less than any pre-release, and is used
+    /// for upper bounds that exclude the pre-releases of the bound (e.g., the
+    /// `2.0.0` of `^1.2` doesn't allow `2.0.0-alpha`).
+    Pre(Vec<Ident>),
+    Release,
+    Post(Vec<Ident>),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Version {
+    epoch: u64,
+    release: Vec<u64>,
+    stage: Stage,
+}
+
+impl Version {
+    pub(crate) fn parse(scheme: Scheme, s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let version = match scheme {
+            Scheme::Cargo | Scheme::Semver => parse_semver(s),
+            Scheme::Ruby => parse_ruby(s),
+            Scheme::Pep440 => parse_pep440(s),
+            Scheme::Maven => parse_maven(s),
+        };
+        version.ok_or_else(|| format!("\"{s}\" is not a valid version"))
+    }
+
+    pub(crate) fn is_prerelease(&self) -> bool {
+        matches!(self.stage, Stage::Pre(_))
+    }
+
+    /// Returns the release numbers, e.g., `[1, 2, 3]` for `1.2.3-alpha`.
+    pub(crate) fn release(&self) -> &[u64] {
+        &self.release
+    }
+
+    /// Returns the index of the first release number that differs between
+    /// `self` and `other`, or `None` if the release numbers are equal.
+    ///
+    /// A change of the epoch (e.g., `1.0` to `1!0.5` in PEP 440) precedes all
+    /// release numbers and is reported as a change of the first.
+    pub(crate) fn changed_index(&self, other: &Self) -> Option<usize> {
+        if self.epoch != other.epoch {
+            return Some(0);
+        }
+        let len = self.release.len().max(other.release.len());
+        (0..len).find(|&i| {
+            self.release.get(i).copied().unwrap_or(0) != other.release.get(i).copied().unwrap_or(0)
+        })
+    }
+
+    /// Returns the lowest version whose `i`-th release number is one greater
+    /// than this version's, excluding its pre-releases.
+    fn bump(&self, i: usize) -> Self {
+        let mut release = self.release.clone();
+        release.resize(release.len().max(i + 1), 0);
+        release.truncate(i + 1);
+        release[i] = release[i].saturating_add(1);
+        Self { epoch: self.epoch, release, stage: Stage::Pre(vec![]) }
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.release.len().max(other.release.len());
+        let release = |release: &[u64]| {
+            release.iter().copied().chain(core::iter::repeat(0)).take(len).collect::<Vec<_>>()
+        };
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| release(&self.release).cmp(&release(&other.release)))
+            .then_with(|| self.stage.cmp(&other.stage))
+    }
+}
+
+fn parse_numbers(s: &str) -> Option<Vec<u64>> {
+    s.split('.').map(|n| if n.is_empty() { None } else { n.parse().ok() }).collect()
+}
+
+/// Parses `[v]1.2.3[-pre.1][+build]`.
+fn parse_semver(s: &str) -> Option<Version> {
+    let s = s.strip_prefix(['v', 'V']).unwrap_or(s);
+    let s = s.split_once('+').map_or(s, |(s, _)| s);
+    let (release, pre) = match s.split_once('-') {
+        Some((release, pre)) => (release, Some(pre)),
+        None => (s, None),
+    };
+    let stage = match pre {
+        Some("") => return None,
+        Some(pre) => Stage::Pre(pre.split('.').map(Ident::new).collect()),
+        None => Stage::Release,
+    };
+    Some(Version { epoch: 0, release: parse_numbers(release)?, stage })
+}
+
+/// Splits `s` into runs of ASCII digits and runs of other characters,
+/// dropping `separators`.
+fn tokenize<'a>(s: &'a str, separators: &'a [char]) -> impl Iterator<Item = &'a str> {
+    s.split(separators).flat_map(|s| {
+        let mut rest = s;
+        core::iter::from_fn(move || {
+            let first = rest.chars().next()?;
+            let end = rest
+                .find(|c: char| c.is_ascii_digit() != first.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (token, tail) = rest.split_at(end);
+            rest = tail;
+            Some(token)
+        })
+    })
+}
+
+/// Parses RubyGems versions, e.g., `1.2.3`, `1.2.3.pre1`, and `1.2.3-rc.1`.
+fn parse_ruby(s: &str) -> Option<Version> {
+    if !s.starts_with(|c: char| c.is_ascii_digit())
+        || !s.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+    {
+        return None;
+    }
+    // RubyGems treats `-` as `.pre.`.
+    let s = s.replace('-', ".pre.");
+    let mut release = vec![];
+    let mut pre = vec![];
+    for token in tokenize(&s, &['.']) {
+        match Ident::new(token) {
+            Ident::Num(n) if pre.is_empty() => release.push(n),
+            ident => pre.push(ident),
+        }
+    }
+    let stage = if pre.is_empty() { Stage::Release } else { Stage::Pre(pre) };
+    Some(Version { epoch: 0, release, stage })
+}
+
+/// Parses PEP 440 versions, e.g., `1!2.0`, `1.0rc1`, `1.0.post1`, and
+/// `1.0.dev1`.
+fn parse_pep440(s: &str) -> Option<Version> {
+    let s = s.to_ascii_lowercase();
+    let s = s.strip_prefix('v').unwrap_or(&s);
+    // Local versions are ignored.
+    let s = s.split_once('+').map_or(s, |(s, _)| s);
+    let (epoch, s) = match s.split_once('!') {
+        Some((epoch, s)) => (epoch.parse().ok()?, s),
+        None => (0, s),
+    };
+    let end = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let (release, mut rest) = s.split_at(end);
+    let release = parse_numbers(release.strip_suffix('.').unwrap_or(release))?;
+    let pre = pep440_segment(&mut rest, &["alpha", "a", "beta", "b", "preview", "pre", "rc", "c"])
+        .map(|(label, n)| match label {
+            "alpha" | "a" => (1, n),
+            "beta" | "b" => (2, n),
+            _ => (3, n),
+        });
+    // `1.0-1` is an implicit post-release.
+    let post = match rest.strip_prefix('-') {
+        Some(s) if s.starts_with(|c: char| c.is_ascii_digit()) => {
+            let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            rest = &s[end..];
+            Some(s[..end].parse().ok()?)
+        }
+        _ => pep440_segment(&mut rest, &["post", "rev", "r"]).map(|(_, n)| n),
+    };
+    let dev = pep440_segment(&mut rest, &["dev"]).map(|(_, n)| n);
+    if !rest.is_empty() {
+        return None;
+    }
+    // A development release precedes the release it is for.
+    let dev_key = Ident::Num(dev.unwrap_or(u64::MAX));
+    let stage = match (pre, post, dev) {
+        (Some((rank, n)), ..) => Stage::Pre(vec![Ident::Num(rank), Ident::Num(n), dev_key]),
+        (None, Some(post), _) => Stage::Post(vec![Ident::Num(post), dev_key]),
+        (None, None, Some(_)) => Stage::Pre(vec![Ident::Num(0), dev_key]),
+        (None, None, None) => Stage::Release,
+    };
+    Some(Version { epoch, release, stage })
+}
+
+/// Parses an optional `[separator]<label>[separator][number]` segment of a
+/// PEP 440 version at the start of `rest`. The number defaults to 0.
+fn pep440_segment<'a>(rest: &mut &str, labels: &[&'a str]) -> Option<(&'a str, u64)> {
+    let s = rest.trim_start_matches(['.', '-', '_']);
+    let &label = labels.iter().find(|&&label| s.starts_with(label))?;
+    let s = s[label.len()..].trim_start_matches(['.', '-', '_']);
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let n = if end == 0 { 0 } else { s[..end].parse().ok()? };
+    *rest = &s[end..];
+    Some((label, n))
+}
+
+/// Parses Maven versions, e.g., `1.2.3`, `1.2.3-SNAPSHOT`, and
+/// `1.0.0.RELEASE`.
+fn parse_maven(s: &str) -> Option<Version> {
+    if !s.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let s = s.to_ascii_lowercase();
+    let mut tokens = tokenize(&s, &['.', '-', '_']).peekable();
+    let mut release = vec![];
+    while let Some(n) = tokens.next_if(|t| t.starts_with(|c: char| c.is_ascii_digit())) {
+        release.push(n.parse().ok()?);
+    }
+    let rest: Vec<Ident> = tokens.map(Ident::new).collect();
+    let rank = match rest.first() {
+        Some(Ident::Str(qualifier)) => match &**qualifier {
+            "alpha" | "a" => Some(1),
+            "beta" | "b" => Some(2),
+            "milestone" | "m" => Some(3),
+            "rc" | "cr" => Some(4),
+            "snapshot" => Some(5),
+            "ga" | "final" | "release" => None,
+            _ => Some(u64::MAX),
+        },
+        _ => Some(u64::MAX),
+    };
+    let stage = match rank {
+        _ if rest.is_empty() => Stage::Release,
+        None if rest.len() == 1 => Stage::Release,
+        Some(rank @ 1..=5) => {
+            let mut pre = vec![Ident::Num(rank)];
+            pre.extend(rest.into_iter().skip(1));
+            Stage::Pre(pre)
+        }
+        _ => Stage::Post(rest),
+    };
+    Some(Version { epoch: 0, release, stage })
+}
+
+#[derive(Debug, Clone)]
+enum Bound {
+    Unbounded,
+    Included(Version),
+    Excluded(Version),
+}
+
+/// A range of versions, or its complement if `negated` is `true`.
+#[derive(Debug, Clone)]
+struct Range {
+    lower: Bound,
+    upper: Bound,
+    negated: bool,
+}
+
+impl Range {
+    fn any() -> Self {
+        Self { lower: Bound::Unbounded, upper: Bound::Unbounded, negated: false }
+    }
+
+    fn exact(version: Version) -> Self {
+        Self {
+            lower: Bound::Included(version.clone()),
+            upper: Bound::Included(version),
+            negated: false,
+        }
+    }
+
+    fn between(lower: Version, upper: Version) -> Self {
+        Self { lower: Bound::Included(lower), upper: Bound::Excluded(upper), negated: false }
+    }
+
+    fn contains(&self, version: &Version) -> bool {
+        let lower = match &self.lower {
+            Bound::Unbounded => true,
+            Bound::Included(v) => version >= v,
+            Bound::Excluded(v) => version > v,
+        };
+        let upper = match &self.upper {
+            Bound::Unbounded => true,
+            Bound::Included(v) => version <= v,
+            Bound::Excluded(v) => version < v,
+        };
+        (lower && upper) != self.negated
+    }
+}
+
+/// A version requirement: a list of alternatives, each of which is a list of
+/// ranges that a matching version must all be in.
+#[derive(Debug, Clone)]
+pub(crate) struct Requirement {
+    alternatives: Vec<Vec<Range>>,
+}
+
+impl Requirement {
+    pub(crate) fn parse(scheme: Scheme, s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("requirement is empty".into());
+        }
+        let alternatives = if scheme == Scheme::Maven && s.starts_with(['[', '(']) {
+            parse_maven_ranges(s)?
+        } else {
+            // `|` is accepted by Composer.
+            s.split("||")
+                .flat_map(|s| s.split('|'))
+                .map(|s| parse_ranges(scheme, s))
+                .collect::<Result<_, _>>()?
+        };
+        Ok(Self { alternatives })
+    }
+
+    pub(crate) fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|ranges| ranges.iter().all(|range| range.contains(version)))
+    }
+}
+
+/// A version in a requirement, which may be partial (e.g., `1.2`) or end with
+/// a wildcard (e.g., `1.2.*`).
+struct Partial {
+    version: Version,
+    /// The number of release numbers specified.
+    len: usize,
+    wildcard: bool,
+}
+
+impl Partial {
+    fn parse(scheme: Scheme, s: &str) -> Result<Option<Self>, String> {
+        let trimmed = s.trim_end_matches(['*', 'x', 'X', '.']);
+        let wildcard =
+            trimmed.len() != s.len() && (trimmed.is_empty() || s[trimmed.len()..].starts_with('.'));
+        if trimmed.is_empty() {
+            return if wildcard { Ok(None) } else { Err("missing version".into()) };
+        }
+        let s = if wildcard { trimmed } else { s };
+        let version = Version::parse(scheme, s)?;
+        let len = version.release.len();
+        if wildcard && version.stage != Stage::Release {
+            return Err(format!("\"{s}\" is not a valid version prefix"));
+        }
+        Ok(Some(Self { version, len, wildcard }))
+    }
+
+    /// Returns `true` if the version stands for a range of versions, e.g.,
+    /// `1.2` in npm means `1.2.x`.
+    fn is_range(&self, scheme: Scheme) -> bool {
+        self.wildcard
+            || matches!(scheme, Scheme::Cargo | Scheme::Semver)
+                && self.len < 3
+                && self.version.stage == Stage::Release
+    }
+
+    /// Returns the lowest version greater than all versions in the range.
+    fn upper(&self) -> Version {
+        self.version.bump(self.len - 1)
+    }
+}
+
+const OPERATORS: &[&str] = &["===", "==", "!=", ">=", "<=", "~>", "~=", "^", "~", ">", "<", "="];
+
+/// Parses a list of comparators separated by commas or whitespace, or a hyphen
+/// range (e.g., `1.2 - 2.3`).
+fn parse_ranges(scheme: Scheme, s: &str) -> Result<Vec<Range>, String> {
+    if let Some((lower, upper)) = s.split_once(" - ") {
+        return Ok(vec![
+            parse_comparator(scheme, ">=", lower.trim())?,
+            parse_comparator(scheme, "<=", upper.trim())?,
+        ]);
+    }
+    let mut ranges = vec![];
+    let mut tokens = s.split(|c: char| c == ',' || c.is_whitespace()).filter(|t| !t.is_empty());
+    while let Some(token) = tokens.next() {
+        let op = OPERATORS.iter().find(|&&op| token.starts_with(op)).copied().unwrap_or("");
+        let version = match &token[op.len()..] {
+            // An operator followed by whitespace, e.g., `>= 1.0`.
+            "" if !op.is_empty() => tokens.next().ok_or("missing version")?,
+            version => version,
+        };
+        ranges.push(parse_comparator(scheme, op, version)?);
+    }
+    if ranges.is_empty() {
+        return Err("requirement is empty".into());
+    }
+    Ok(ranges)
+}
+
+fn parse_comparator(scheme: Scheme, op: &str, s: &str) -> Result<Range, String> {
+    let Some(p) = Partial::parse(scheme, s)? else {
+        return match op {
+            "" | "=" | "==" | ">=" => Ok(Range::any()),
+            _ => Err(format!("invalid requirement \"{op}{s}\"")),
+        };
+    };
+    let is_range = p.is_range(scheme);
+    let range = match op {
+        "" if scheme == Scheme::Cargo => caret(&p),
+        "" | "=" | "==" | "===" if is_range => Range::between(p.version.clone(), p.upper()),
+        "" | "=" | "==" | "===" => Range::exact(p.version),
+        "!=" => {
+            let range = if is_range {
+                Range::between(p.version.clone(), p.upper())
+            } else {
+                Range::exact(p.version)
+            };
+            Range { negated: true, ..range }
+        }
+        ">" if is_range => Range { lower: Bound::Included(p.upper()), ..Range::any() },
+        // In PEP 440, `>V` doesn't match the post-releases of `V` unless `V`
+        // is a post-release.
+        ">" if scheme == Scheme::Pep440 && p.version.stage == Stage::Release => {
+            let last = Stage::Post(vec![Ident::Num(u64::MAX), Ident::Num(u64::MAX)]);
+            Range { lower: Bound::Excluded(Version { stage: last, ..p.version }), ..Range::any() }
+        }
+        ">" => Range { lower: Bound::Excluded(p.version), ..Range::any() },
+        ">=" => Range { lower: Bound::Included(p.version), ..Range::any() },
+        "<" => Range { upper: Bound::Excluded(p.version), ..Range::any() },
+        "<=" if is_range => Range { upper: Bound::Excluded(p.upper()), ..Range::any() },
+        "<=" => Range { upper: Bound::Included(p.version), ..Range::any() },
+        "^" => caret(&p),
+        "~" => {
+            let upper = p.version.bump(p.len.min(2) - 1);
+            Range::between(p.version, upper)
+        }
+        "~>" | "~=" => {
+            if op == "~=" && p.len < 2 {
+                return Err(format!("\"~={s}\" requires at least two release numbers"));
+            }
+            let upper = p.version.bump(p.len.saturating_sub(2));
+            Range::between(p.version, upper)
+        }
+        _ => unreachable!(),
+    };
+    Ok(range)
+}
+
+/// Returns the range of `^version`: versions that don't change the leftmost
+/// non-zero release number.
+fn caret(p: &Partial) -> Range {
+    let i = p.version.release[..p.len].iter().position(|&n| n != 0).unwrap_or(p.len - 1);
+    Range::between(p.version.clone(), p.version.bump(i))
+}
+
+/// Parses Maven version ranges, e.g., `[1.0,2.0)`, `(,1.0],[1.2,)`, and
+/// `[1.5]`.
+fn parse_maven_ranges(s: &str) -> Result<Vec<Vec<Range>>, String> {
+    let mut alternatives = vec![];
+    let mut rest = s;
+    while !rest.is_empty() {
+        let open = if rest.starts_with('[') {
+            true
+        } else if rest.starts_with('(') {
+            false
+        } else {
+            return Err(format!("invalid range in \"{s}\""));
+        };
+        let end = rest.find([']', ')']).ok_or_else(|| format!("unclosed range in \"{s}\""))?;
+        let close = rest[end..].starts_with(']');
+        let version = |s: &str| -> Result<Option<Version>, String> {
+            let s = s.trim();
+            if s.is_empty() { Ok(None) } else { Version::parse(Scheme::Maven, s).map(Some) }
+        };
+        let bound = |v: Option<Version>, inclusive: bool| match v {
+            None => Bound::Unbounded,
+            Some(v) if inclusive => Bound::Included(v),
+            Some(v) => Bound::Excluded(v),
+        };
+        let range = match rest[1..end].split_once(',') {
+            Some((lower, upper)) => Range {
+                lower: bound(version(lower)?, open),
+                upper: bound(version(upper)?, close),
+                negated: false,
+            },
+            None if open && close => {
+                Range::exact(version(&rest[1..end])?.ok_or("missing version")?)
+            }
+            None => return Err(format!("invalid range \"{}\"", &rest[..=end])),
+        };
+        alternatives.push(vec![range]);
+        let tail = rest[end + 1..].trim_start();
+        rest = tail.strip_prefix(',').unwrap_or(tail).trim_start();
+    }
+    Ok(alternatives)
+}
@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Helpers for working with YAML paths (e.g., `updates[2].schedule.interval`)
+// on top of serde_yaml, which doesn't expose source positions of values.
+
+use alloc::{
+    format,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::{
+    cell::Cell,
+    fmt::{self, Write as _},
+};
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess};
+
+use crate::{
+    cst,
+    error::{Class, Error, Location},
+    suggest,
+};
+
+/// A segment of a YAML path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Parses a path in the format used by serde_yaml's error messages.
+///
+/// Parsing stops at the first segment that cannot be represented, e.g., a
+/// non-string key (which serde_yaml displays as `?`).
+pub(crate) fn parse_path(path: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut indices) = part.split_once('[').map_or((part, ""), |(k, i)| (k, i));
+        if key == "?" {
+            break;
+        }
+        if !key.is_empty() {
+            segments.push(Segment::Key(key));
+        }
+        while !indices.is_empty() {
+            let Some((index, rest)) = indices.split_once(']') else { return segments };
+            let Ok(index) = index.parse() else { return segments };
+            segments.push(Segment::Index(index));
+            indices = rest.strip_prefix('[').unwrap_or(rest);
+        }
+    }
+    segments
+}
+
+/// Deserializes `T` from `s`, adding a suggestion to the error message if
+/// possible.
+pub(crate) fn from_str<T: DeserializeOwned>(s: &str) -> Result<T, Error> {
+    serde_yaml::from_str(s).map_err(|e| {
+        let Ok(value) = serde_yaml::from_str::<serde_yaml::Value>(s) else {
+            return Error::yaml(e, Class::Syntax, None);
+        };
+        let (relative, msg) = split_error(&e, &value);
+        let class = Class::of_yaml(&msg);
+        if relative.as_ref().is_some_and(|relative| relative.contains('?')) {
+            return Error::yaml(e, class, relative);
+        }
+        let path = parse_path(relative.as_deref().unwrap_or_default());
+        match suggest::suggest(&msg, get(&value, &path)) {
+            Some(msg) => Locator::new(s).error(&path, class, &msg),
+            None => Error::yaml(e, class, relative),
+        }
+    })
+}
+
+/// Splits the message of serde_yaml's error about `value` into the path and
+/// the message without the path and location.
+///
+/// serde_yaml doesn't expose the path, but prefixes it to the message in the
+/// form of `path: message` if the error is about a value other than the
+/// top-level value. Since keys may contain `": "`, the path is the first
+/// prefix that exists in `value`.
+fn split_error(e: &serde_yaml::Error, value: &serde_yaml::Value) -> (Option<String>, String) {
+    let mut msg = e.to_string();
+    if let Some(location) = e.location() {
+        let suffix = format!(" at line {} column {}", location.line(), location.column());
+        if msg.ends_with(&suffix) {
+            msg.truncate(msg.len() - suffix.len());
+        }
+    }
+    let path = msg.match_indices(": ").map(|(i, _)| &msg[..i]).find(|&path| {
+        let segments = parse_path(path);
+        (!segments.is_empty() || path == "?") && get(value, &segments).is_some()
+    });
+    match path {
+        Some(path) => {
+            let path = path.to_string();
+            let msg = msg[path.len() + 2..].into();
+            (Some(path), msg)
+        }
+        None => (None, msg),
+    }
+}
+
+/// Returns the value at `path` in `value`.
+fn get<'a>(
+    mut value: &'a serde_yaml::Value,
+    path: &[Segment<'_>],
+) -> Option<&'a serde_yaml::Value> {
+    for segment in path {
+        value = match segment {
+            Segment::Key(key) => value.get(key)?,
+            Segment::Index(i) => value.get(i)?,
+        };
+    }
+    Some(value)
+}
+
+/// Returns serde's "unknown field" error for the mapping at `path` in `src`.
+pub(crate) fn unknown_field(
+    src: &str,
+    path: &[Segment<'_>],
+    field: &str,
+    expected: &'static [&'static str],
+) -> Error {
+    let msg = <serde_yaml::Error as de::Error>::unknown_field(field, expected).to_string();
+    let msg = suggest::suggest(&msg, None).unwrap_or(msg);
+    Locator::new(src).error(path, Class::UnknownField, &msg)
+}
+
+/// Resolves the locations of values in the input.
+///
+/// The input is parsed once into a [`cst`] span tree. If the input uses YAML
+/// that the span tree doesn't support, or the value is inside a flow
+/// collection, serde_yaml walks the input to the value instead.
+pub(crate) struct Locator<'a> {
+    src: &'a str,
+    root: Option<cst::Mapping>,
+}
+
+impl<'a> Locator<'a> {
+    pub(crate) fn new(src: &'a str) -> Self {
+        Self { src, root: cst::parse(src).ok() }
+    }
+
+    /// Deserializes `value`, which is the value at `path` in the input except
+    /// that the values of the `replaced` keys have been replaced, and reports
+    /// errors with the path and location in the input.
+    pub(crate) fn deserialize<T: DeserializeOwned>(
+        &self,
+        path: &[Segment<'_>],
+        value: &serde_yaml::Value,
+        replaced: &[&str],
+    ) -> Result<T, Error> {
+        let s = match self.text(path, value, replaced) {
+            Some(s) => s,
+            // Round-trip through a string so that serde_yaml tracks the path of the error.
+            None => serde_yaml::to_string(value).map_err(Error::new)?,
+        };
+        serde_yaml::from_str(&s).map_err(|e| {
+            let (relative, msg) = split_error(&e, value);
+            let class = Class::of_yaml(&msg);
+            let relative = relative.unwrap_or_default();
+            let relative = parse_path(&relative);
+            let msg = suggest::suggest(&msg, get(value, &relative)).unwrap_or(msg);
+            let mut path = path.to_vec();
+            path.extend(relative);
+            self.error(&path, class, &msg)
+        })
+    }
+
+    /// Returns the text of the value at `path` in the input (see
+    /// [`deserialize`](Self::deserialize)), indented so that it can be parsed
+    /// on its own.
+    ///
+    /// Deserializing from the input, rather than from `value`, interprets
+    /// scalars in exactly the same way as deserializing the whole input.
+    fn text(
+        &self,
+        path: &[Segment<'_>],
+        value: &serde_yaml::Value,
+        replaced: &[&str],
+    ) -> Option<String> {
+        let root = self.root.as_ref()?;
+        let span = root.span(path)?;
+        let mut edits = Vec::with_capacity(replaced.len());
+        if !replaced.is_empty() {
+            let mapping = root.mapping(path)?;
+            for &key in replaced {
+                let replacement = serde_yaml::to_string(value.get(key)?).ok()?;
+                edits.push((mapping.get(key)?.value_span(), replacement));
+            }
+        }
+        edits.sort_by_key(|(span, _)| span.start);
+        let line_start = self.src[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let mut s = " ".repeat(self.src[line_start..span.start].chars().count());
+        let mut offset = span.start;
+        for (span, replacement) in edits {
+            s.push_str(&self.src[offset..span.start]);
+            s.push(' ');
+            s.push_str(replacement.trim_end());
+            offset = span.end;
+        }
+        s.push_str(&self.src[offset..span.end]);
+        Some(s)
+    }
+
+    /// Returns an error of the given class and message at the value at
+    /// `path`.
+    ///
+    /// If `path` doesn't exist in the input, the error is reported at the
+    /// nearest existing ancestor.
+    pub(crate) fn error(&self, path: &[Segment<'_>], class: Class, msg: &str) -> Error {
+        if let Some((index, len)) = self.root.as_ref().and_then(|root| root.locate(self.src, path))
+        {
+            let (line, column) = line_column(self.src, index);
+            let location = Location::new(line, column, index, display_path(&path[..len]));
+            return Error::located(class, msg, location);
+        }
+        let depth = Cell::new(0);
+        let seed = Locate { path, msg, depth: &depth };
+        match seed.deserialize(serde_yaml::Deserializer::from_str(self.src)) {
+            Err(e) => Error::yaml(e, class, display_path(&path[..depth.get()])),
+            Ok(()) => Error::parse(class, msg),
+        }
+    }
+}
+
+/// Returns the line and column (both 1-based) of `index`, counted in the same
+/// way as libyaml.
+fn line_column(src: &str, index: usize) -> (usize, usize) {
+    let before = &src[..index];
+    let line_start = before.rfind(['\n', '\r']).map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + before.matches('\r').count()
+        - before.matches("\r\n").count();
+    (line + 1, before[line_start..].chars().count() + 1)
+}
+
+/// Formats `path` in the format used by serde_yaml's error messages, or
+/// returns `None` if `path` is empty.
+fn display_path(path: &[Segment<'_>]) -> Option<String> {
+    let mut s = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) if s.is_empty() => s.push_str(key),
+            Segment::Key(key) => {
+                s.push('.');
+                s.push_str(key);
+            }
+            Segment::Index(i) => {
+                let _ = write!(s, "[{i}]");
+            }
+        }
+    }
+    if s.is_empty() { None } else { Some(s) }
+}
+
+/// Walks to the value at `path` (or, if it doesn't exist, its nearest existing
+/// ancestor) and fails there with `msg`, so that serde_yaml attaches the
+/// location of the value to the error.
+struct Locate<'a> {
+    path: &'a [Segment<'a>],
+    msg: &'a str,
+    /// The number of segments of `path` walked.
+    depth: &'a Cell<usize>,
+}
+
+impl Locate<'_> {
+    fn scalar<E: de::Error>(self) -> Result<(), E> {
+        Err(E::custom(self.msg))
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Locate<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for Locate<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_i128<E: de::Error>(self, _: i128) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_u128<E: de::Error>(self, _: u128) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.scalar()
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let Some((first, rest)) = self.path.split_first() else {
+            return Err(de::Error::custom(self.msg));
+        };
+        let mut i = 0;
+        loop {
+            if matches!(first, Segment::Index(n) if *n == i) {
+                self.depth.set(self.depth.get() + 1);
+                let seed = Locate { path: rest, msg: self.msg, depth: self.depth };
+                return seq.next_element_seed(seed).map(drop);
+            }
+            if seq.next_element::<IgnoredAny>()?.is_none() {
+                return Err(de::Error::custom(self.msg));
+            }
+            i += 1;
+        }
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Some((first, rest)) = self.path.split_first() else {
+            return Err(de::Error::custom(self.msg));
+        };
+        while let Some(key) = map.next_key::<serde_yaml::Value>()? {
+            if matches!(first, Segment::Key(k) if key.as_str() == Some(k)) {
+                self.depth.set(self.depth.get() + 1);
+                return map.next_value_seed(Locate {
+                    path: rest,
+                    msg: self.msg,
+                    depth: self.depth,
+                });
+            }
+            map.next_value::<IgnoredAny>()?;
+        }
+        Err(de::Error::custom(self.msg))
+    }
+}
@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use dependabot_config::{Dependabot, v1, v2};
+use dependabot_config::{Dependabot, Value, v1, v2};
 use fs_err as fs;
 use serde::de::Deserialize;
 use serde_derive::{Deserialize, Serialize};
@@ -18,7 +21,7 @@ fn fixtures_dir() -> PathBuf {
 #[cfg_attr(miri, ignore)] // Miri is too slow
 fn test_v2() {
     let v2_test_data = &*fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
-    assert_eq!(from_str::<serde_yaml::Value>(v2_test_data).len(), 23);
+    assert_eq!(from_str::<serde_yaml::Value>(v2_test_data).len(), 28);
     for case in from_str::<v2::Dependabot>(v2_test_data) {
         case.to_string();
     }
@@ -49,16 +52,825 @@ fn test_v2() {
 
     let v2_registries_test_data =
         &*fs::read_to_string(fixtures_dir().join("v2_registries.yml")).unwrap();
-    assert_eq!(from_str::<serde_yaml::Value>(v2_registries_test_data).len(), 17);
+    assert_eq!(from_str::<serde_yaml::Value>(v2_registries_test_data).len(), 22);
     for case in from_str::<TestRegistriesV2>(v2_registries_test_data) {
         serde_yaml::to_string(&case).unwrap();
     }
     for case in from_str::<serde_yaml::Value>(v2_registries_test_data) {
         let s = serde_yaml::to_string(&case).unwrap();
-        serde_yaml::to_string(&serde_yaml::from_str::<TestRegistriesV2>(&s).unwrap()).unwrap();
+        let s =
+            serde_yaml::to_string(&serde_yaml::from_str::<TestRegistriesV2>(&s).unwrap()).unwrap();
+        // All per-type fields survive round-trip.
+        assert_eq!(serde_yaml::from_str::<serde_yaml::Value>(&s).unwrap(), case);
     }
 }
 
+#[test]
+fn test_v2_cooldown() {
+    let config = |days: u32| {
+        format!(
+            "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+    cooldown:
+      semver-major-days: {days}
+"
+        )
+    };
+    let dependabot = config(90).parse::<v2::Dependabot>().unwrap();
+    assert_eq!(dependabot.updates[0].cooldown.as_ref().unwrap().semver_major_days, Some(90));
+    for days in [0, 91] {
+        let e = config(days).parse::<v2::Dependabot>().unwrap_err();
+        assert!(
+            e.to_string().starts_with(&format!(
+                "updates[0].cooldown: The property \
+                 'cooldown/semver-major-days' value \"{days}\" did not match"
+            )),
+            "{e}"
+        );
+    }
+}
+
+#[test]
+fn test_v2_validate() {
+    let config: v2::Dependabot = "version: 2
+registries:
+  npm:
+    type: npm-registry
+    url: https://registry.npmjs.org
+    token: ${{secrets.NPM_TOKEN}}
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directory: /
+    target-branch: dev
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directories: [/a, /]
+    schedule:
+      interval: daily
+  - package-ecosystem: npm
+    directory: /
+    directories: [/a]
+    schedule:
+      interval: daily
+  - package-ecosystem: pip
+    registries: [npm-github, npm]
+    schedule:
+      interval: cron
+  - package-ecosystem: docker
+    directories: []
+    schedule:
+      interval: daily
+      day: monday
+      cronjob: '0 9 * * *'
+  - package-ecosystem: gomod
+    directory: /
+    schedule:
+      interval: cron
+      cronjob: '0 9 * * *'
+      time: '09:00'
+      timezone: Asia/Tokyo
+"
+    .parse()
+    .unwrap();
+    let diagnostics = config.validate();
+    assert_eq!(diagnostics.iter().map(|d| (d.code, &*d.path)).collect::<Vec<_>>(), [
+        (v2::DiagnosticCode::DuplicateUpdate, "updates[2].directories[1]"),
+        (v2::DiagnosticCode::ConflictingDirectory, "updates[3]"),
+        (v2::DiagnosticCode::MissingDirectory, "updates[4]"),
+        (v2::DiagnosticCode::UndefinedRegistry, "updates[4].registries[0]"),
+        (v2::DiagnosticCode::MissingCronjob, "updates[4].schedule"),
+        (v2::DiagnosticCode::EmptyDirectories, "updates[5].directories"),
+        (v2::DiagnosticCode::UnexpectedCronjob, "updates[5].schedule.cronjob"),
+        (v2::DiagnosticCode::UnexpectedTime, "updates[6].schedule.time"),
+    ]);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "updates[2].directories[1]: The combination of 'package-ecosystem', 'directory', and 'target-branch' must be unique, but updates[0] has the same combination"
+    );
+    assert_eq!(diagnostics[0].code.to_string(), "duplicate-update");
+
+    for case in
+        from_str::<v2::Dependabot>(&fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap())
+    {
+        assert_eq!(case.validate(), []);
+    }
+}
+
+#[test]
+fn test_v2_update_registries() {
+    let v2_test_data = &*fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
+    let config = from_str::<v2::Dependabot>(v2_test_data).pop().unwrap();
+    assert_eq!(
+        config.updates[0].registries,
+        Some(v2::UpdateRegistries::Names(vec!["npm-npmjs".to_owned()]))
+    );
+    assert_eq!(config.updates[1].registries, Some(v2::UpdateRegistries::All));
+    let urls = |update: &v2::Update| {
+        update
+            .resolve_registries(&config)
+            .into_iter()
+            .map(|r| r.url.as_deref().unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(urls(&config.updates[0]), ["https://registry.npmjs.org"]);
+    assert_eq!(urls(&config.updates[1]), [
+        "https://registry.npmjs.org",
+        "https://npm.pkg.github.com"
+    ]);
+    let s = config.to_string();
+    assert!(s.contains("registries: '*'"), "{s}");
+    assert!(s.contains("    registries:\n      - npm-npmjs"), "{s}");
+
+    let e = "version: 2
+updates:
+  - package-ecosystem: npm
+    directory: /
+    registries: all
+    schedule:
+      interval: daily
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert!(
+        e.to_string().starts_with(
+            "updates[0].registries: The property 'registries' value \"all\" did not match: *"
+        ),
+        "{e}"
+    );
+}
+
+#[test]
+fn test_v2_typed_registry() {
+    let v2_registries_test_data =
+        &*fs::read_to_string(fixtures_dir().join("v2_registries.yml")).unwrap();
+    for case in from_str::<TestRegistriesV2>(v2_registries_test_data) {
+        for (_, registry) in case.registries {
+            let typed = v2::TypedRegistry::try_from(registry.clone()).unwrap();
+            assert_eq!(typed.type_(), registry.type_);
+            let untyped = v2::Registry::from(typed.clone());
+            assert_eq!(
+                serde_yaml::to_string(&untyped).unwrap(),
+                serde_yaml::to_string(&registry).unwrap()
+            );
+            assert_eq!(
+                serde_yaml::to_string(&typed).unwrap(),
+                serde_yaml::to_string(&registry).unwrap()
+            );
+        }
+    }
+
+    let typed = serde_yaml::from_str::<v2::TypedRegistry>(
+        "type: npm-registry\nurl: https://npm.pkg.github.com\ntoken: ${{secrets.TOKEN}}\n",
+    )
+    .unwrap();
+    assert_eq!(typed, v2::TypedRegistry::NpmRegistry {
+        url: "https://npm.pkg.github.com".to_owned(),
+        credentials: v2::RegistryCredentials::Token { token: "${{secrets.TOKEN}}".to_owned() },
+        replaces_base: None,
+    });
+
+    for (s, msg) in [
+        (
+            "type: git\nusername: x-access-token\npassword: ${{secrets.TOKEN}}\n",
+            "registry of type 'git' requires the 'url' property",
+        ),
+        (
+            "type: npm-registry\nurl: https://npm.pkg.github.com\nusername: octocat\npassword: ${{secrets.PASSWORD}}\ntoken: ${{secrets.TOKEN}}\n",
+            "registry of type 'npm-registry' cannot have both the 'token' property and the 'username'/'password' properties",
+        ),
+        (
+            "type: nuget-feed\nurl: https://nuget.example.com\nusername: octocat\n",
+            "registry of type 'nuget-feed' requires either both the 'username' and 'password' properties or the 'token' property",
+        ),
+        (
+            "type: terraform-registry\nurl: https://terraform.example.com\ntoken: ${{secrets.TOKEN}}\nreplaces-base: true\n",
+            "registry of type 'terraform-registry' does not support the 'replaces-base' property",
+        ),
+        (
+            "type: hex-organization\norganization: github\nkey: ${{secrets.KEY}}\nurl: https://hex.pm\n",
+            "registry of type 'hex-organization' does not support the 'url' property",
+        ),
+    ] {
+        let registry = serde_yaml::from_str::<v2::Registry>(s).unwrap();
+        assert_eq!(v2::TypedRegistry::try_from(registry).unwrap_err().to_string(), msg);
+        assert_eq!(serde_yaml::from_str::<v2::TypedRegistry>(s).unwrap_err().to_string(), msg);
+    }
+}
+
+#[test]
+fn test_v2_path_pattern() {
+    let matches =
+        |pattern: &str, path: &str| v2::PathPattern::new(pattern).matches(Path::new(path));
+
+    assert!(!v2::PathPattern::new("/apps/web").is_glob());
+    assert!(v2::PathPattern::new("/apps/*").is_glob());
+
+    assert!(matches("/", "/"));
+    assert!(matches("/", ""));
+    assert!(!matches("/", "/apps"));
+    assert!(matches("/apps/web", "/apps/web/"));
+    assert!(matches("/apps/web", "apps/web"));
+    assert!(!matches("/apps/web", "/apps/web/src"));
+    assert!(matches("/apps/*", "/apps/web"));
+    assert!(!matches("/apps/*", "/apps"));
+    assert!(!matches("/apps/*", "/apps/web/src"));
+    assert!(matches("/lib-*", "/lib-a"));
+    assert!(matches("/lib-?", "/lib-a"));
+    assert!(!matches("/lib-?", "/lib-ab"));
+    assert!(matches("/**/", "/"));
+    assert!(matches("/**/", "/apps/web/src"));
+    assert!(matches("/apps/**/src", "/apps/src"));
+    assert!(matches("/apps/**/src", "/apps/web/src"));
+    assert!(!matches("/apps/**/src", "/apps/web/lib"));
+    assert!(matches("vendor/**", "vendor/a/b.lock"));
+
+    let mut update =
+        v2::Update::new(v2::PackageEcosystem::Cargo, "/", v2::Schedule::new(v2::Interval::Daily));
+    assert!(update.matches_directory(Path::new("/")));
+    assert!(!update.matches_directory(Path::new("/apps/web")));
+    update.directory = None;
+    update.directories =
+        Some(vec![v2::PathPattern::new("/apps/*"), v2::PathPattern::new("/tools")]);
+    assert!(!update.matches_directory(Path::new("/")));
+    assert!(update.matches_directory(Path::new("/apps/web")));
+    assert!(update.matches_directory(Path::new("/tools")));
+    update.exclude_paths =
+        Some(vec![v2::PathPattern::new("/apps/legacy"), v2::PathPattern::new("/tools/**")]);
+    assert!(update.matches_directory(Path::new("/apps/web")));
+    assert!(!update.matches_directory(Path::new("/apps/legacy")));
+    assert!(!update.matches_directory(Path::new("/tools")));
+    update.directories = Some(vec![v2::PathPattern::new("/**")]);
+    update.exclude_paths = Some(vec![v2::PathPattern::new("/apps/legacy")]);
+    assert!(update.matches_directory(Path::new("/apps")));
+    assert!(!update.matches_directory(Path::new("/apps/legacy/src")));
+}
+
+#[test]
+fn test_v2_name_pattern() {
+    let matches = |pattern: &str, name: &str| v2::NamePattern::new(pattern).matches(name);
+
+    assert!(!v2::NamePattern::new("serde").is_glob());
+    assert!(v2::NamePattern::new("serde*").is_glob());
+    assert_eq!(v2::NamePattern::new("@types/*").to_string(), "@types/*");
+
+    assert!(matches("serde", "serde"));
+    assert!(matches("Serde", "serde"));
+    assert!(matches("serde", "SERDE"));
+    assert!(!matches("serde", "serde_json"));
+    assert!(matches("serde*", "serde"));
+    assert!(matches("serde*", "serde_json"));
+    assert!(!matches("serde*", "my-serde"));
+    assert!(matches("*serde*", "my-serde"));
+    assert!(matches("*", ""));
+    assert!(matches("*", "@types/node"));
+    assert!(matches("@types/*", "@types/node"));
+    assert!(matches("@types/*", "@Types/Node"));
+    assert!(!matches("@types/*", "@typescript-eslint/parser"));
+    assert!(matches("@*/core", "@angular/core"));
+    assert!(!matches("@*/core", "@angular/core-js"));
+    assert!(matches("a*b*b", "abb"));
+    assert!(!matches("a*b*b", "ab"));
+    assert!(matches("aws-sdk-?", "aws-sdk-?"));
+    assert!(!matches("aws-sdk-?", "aws-sdk-s"));
+
+    let ignore = v2::Ignore::new("@types/*".to_owned());
+    assert!(ignore.matches_name("@types/node"));
+    assert!(!ignore.matches_name("node"));
+    assert_eq!(ignore.name_pattern(), v2::NamePattern::new("@types/*"));
+    let allow: v2::Allow = "dependency-type: direct".parse().unwrap();
+    assert!(allow.matches_name("node"));
+    assert_eq!(allow.name_pattern(), None);
+    let allow: v2::Allow = "dependency-name: 'aws-*'".parse().unwrap();
+    assert!(allow.matches_name("AWS-SDK"));
+    assert!(!allow.matches_name("node"));
+    assert!(allow.name_pattern().unwrap().matches("aws-sdk"));
+}
+
+#[test]
+fn test_v2_version_requirement() {
+    use v2::PackageEcosystem::{Bundler, Cargo, Maven, Npm, Pip};
+
+    let version = |ecosystem: &v2::PackageEcosystem, v: &str| {
+        v2::Version::parse(ecosystem, v).unwrap_or_else(|e| panic!("{v}: {e}"))
+    };
+    let matches = |ecosystem: v2::PackageEcosystem, req: &str, v: &str| {
+        v2::VersionRequirement::parse(&ecosystem, req)
+            .unwrap_or_else(|e| panic!("{req}: {e}"))
+            .matches(&version(&ecosystem, v))
+    };
+
+    // Ordering.
+    assert_eq!(version(&Npm, "1.2"), version(&Npm, "1.2.0"));
+    assert_eq!(version(&Npm, "v1.2.3+build"), version(&Npm, "1.2.3"));
+    assert!(version(&Npm, "1.0.0-alpha") < version(&Npm, "1.0.0-alpha.1"));
+    assert!(version(&Npm, "1.0.0-alpha.2") < version(&Npm, "1.0.0-alpha.10"));
+    assert!(version(&Npm, "1.0.0-rc.1") < version(&Npm, "1.0.0"));
+    assert!(version(&Npm, "1.9.0") < version(&Npm, "1.10.0"));
+    assert!(version(&Bundler, "1.0.0.pre1") < version(&Bundler, "1.0.0"));
+    assert!(version(&Bundler, "1.0.0-rc.1").is_prerelease());
+    assert!(version(&Pip, "1.0.dev1") < version(&Pip, "1.0a1"));
+    assert!(version(&Pip, "1.0a1") < version(&Pip, "1.0b2"));
+    assert!(version(&Pip, "1.0b2") < version(&Pip, "1.0rc1"));
+    assert!(version(&Pip, "1.0rc1") < version(&Pip, "1.0"));
+    assert!(version(&Pip, "1.0") < version(&Pip, "1.0.post1"));
+    assert!(version(&Pip, "1.0.post1") < version(&Pip, "1.1"));
+    assert!(version(&Pip, "2.0") < version(&Pip, "1!1.0"));
+    assert_eq!(version(&Pip, "1.0-1"), version(&Pip, "1.0.post1"));
+    assert!(version(&Maven, "1.0-alpha-1") < version(&Maven, "1.0-beta-1"));
+    assert!(version(&Maven, "1.0-M1") < version(&Maven, "1.0-RC1"));
+    assert!(version(&Maven, "1.0-RC1") < version(&Maven, "1.0-SNAPSHOT"));
+    assert!(version(&Maven, "1.0-SNAPSHOT") < version(&Maven, "1.0"));
+    assert_eq!(version(&Maven, "1.0.0.RELEASE"), version(&Maven, "1.0"));
+    assert!(version(&Maven, "1.0") < version(&Maven, "1.0-sp1"));
+    assert!(v2::Version::parse(&Npm, "1.x").is_err());
+    assert!(v2::Version::parse(&Pip, "1.0foo").is_err());
+    assert_eq!(version(&Npm, "v1.0").to_string(), "v1.0");
+
+    // Comparisons and wildcards.
+    assert!(matches(Npm, "*", "1.2.3"));
+    assert!(matches(Npm, ">=1.2.3", "1.2.3"));
+    assert!(!matches(Npm, ">1.2.3", "1.2.3"));
+    assert!(matches(Npm, ">= 1.0, < 2", "1.9.9"));
+    assert!(!matches(Npm, ">=1.0 <2", "2.0.0"));
+    assert!(matches(Npm, "1.2.3", "1.2.3"));
+    assert!(!matches(Npm, "1.2.3", "1.2.4"));
+    assert!(matches(Npm, "1.2", "1.2.4"));
+    assert!(matches(Npm, "1.x", "1.9.0"));
+    assert!(!matches(Npm, "1.x", "2.0.0"));
+    assert!(!matches(Npm, "1.x", "2.0.0-alpha"));
+    assert!(matches(Npm, "1.2.*", "1.2.9"));
+    assert!(!matches(Npm, "1.2.*", "1.3.0"));
+    assert!(matches(Npm, ">1.2", "1.3.0"));
+    assert!(!matches(Npm, ">1.2", "1.2.9"));
+    assert!(matches(Npm, "<=1.2", "1.2.9"));
+    assert!(!matches(Npm, "<=1.2", "1.3.0"));
+    assert!(!matches(Npm, "!=1.2.3", "1.2.3"));
+    assert!(matches(Npm, "!=1.2.3", "1.2.4"));
+    assert!(matches(Npm, "1.0 - 2.0", "2.0.5"));
+    assert!(!matches(Npm, "1.0 - 2.0", "2.1.0"));
+    assert!(matches(Npm, "^1.0 || ^3.0", "3.1.0"));
+    assert!(!matches(Npm, "^1.0 || ^3.0", "2.1.0"));
+
+    // Compatible releases.
+    assert!(matches(Npm, "^1.2.3", "1.9.0"));
+    assert!(!matches(Npm, "^1.2.3", "1.2.2"));
+    assert!(!matches(Npm, "^1.2.3", "2.0.0"));
+    assert!(matches(Npm, "^0.2.3", "0.2.9"));
+    assert!(!matches(Npm, "^0.2.3", "0.3.0"));
+    assert!(!matches(Npm, "^0.0.3", "0.0.4"));
+    assert!(matches(Npm, "~1.2.3", "1.2.9"));
+    assert!(!matches(Npm, "~1.2.3", "1.3.0"));
+    assert!(matches(Npm, "~1", "1.9.0"));
+    assert!(matches(Cargo, "1.2", "1.9.0"));
+    assert!(!matches(Cargo, "1.2", "2.0.0"));
+    assert!(!matches(Cargo, "=1.2.3", "1.2.4"));
+    assert!(matches(Bundler, "~> 1.2", "1.9"));
+    assert!(!matches(Bundler, "~> 1.2", "2.0"));
+    assert!(matches(Bundler, "~> 1.2.3", "1.2.9"));
+    assert!(!matches(Bundler, "~> 1.2.3", "1.3.0"));
+    assert!(matches(Bundler, "1.2", "1.2.0"));
+    assert!(!matches(Bundler, "1.2", "1.2.1"));
+    assert!(matches(Bundler, ">= 5.0, < 6", "5.2.1.rc1"));
+    assert!(matches(Pip, "~=1.4.5", "1.4.9"));
+    assert!(!matches(Pip, "~=1.4.5", "1.5.0"));
+    assert!(matches(Pip, "==1.4.*", "1.4.2"));
+    assert!(!matches(Pip, "!=1.4.*", "1.4.2"));
+    assert!(matches(Pip, ">=2.0,<3.0", "2.5rc1"));
+    assert!(!matches(Pip, ">1.7", "1.7.post1"));
+    assert!(!matches(Pip, ">1.7", "1.7.0.post2.dev1"));
+    assert!(matches(Pip, ">1.7", "1.7.0.1"));
+    assert!(matches(Pip, ">1.7.post1", "1.7.post2"));
+    assert!(!matches(Pip, ">1.7.post1", "1.7.post1"));
+    assert!(matches(Pip, ">=1.7", "1.7.post1"));
+
+    // Maven ranges.
+    assert!(matches(Maven, "[1.0,2.0)", "1.5"));
+    assert!(!matches(Maven, "[1.0,2.0)", "2.0"));
+    assert!(matches(Maven, "(,1.0],[1.2,)", "1.0"));
+    assert!(!matches(Maven, "(,1.0],[1.2,)", "1.1"));
+    assert!(matches(Maven, "(,1.0],[1.2,)", "3.0"));
+    assert!(matches(Maven, "[1.5]", "1.5.0"));
+    assert!(matches(Maven, "1.5", "1.5"));
+    assert!(matches(Maven, ">= 1.0, < 2.0", "1.2-SNAPSHOT"));
+
+    for (ecosystem, req) in [
+        (Npm, ""),
+        (Npm, ">="),
+        (Npm, "^1.x.y"),
+        (Pip, "~=1"),
+        (Maven, "[1.0"),
+        (Maven, "[1.0,2.0)x"),
+    ] {
+        let e = v2::VersionRequirement::parse(&ecosystem, req).unwrap_err();
+        assert!(
+            e.to_string().starts_with(&format!("invalid version requirement \"{req}\": ")),
+            "{e}"
+        );
+    }
+
+    let mut ignore = v2::Ignore::new("express".to_owned());
+    assert!(ignore.is_ignored(&Npm, "5.0.0").unwrap());
+    ignore.update_types = Some(vec![v2::UpdateType::SemverMajor]);
+    assert!(!ignore.is_ignored(&Npm, "5.0.0").unwrap());
+    ignore.versions = Some(vec!["4.x".to_owned(), ">= 5.1".to_owned()]);
+    assert!(ignore.is_ignored(&Npm, "4.17.1").unwrap());
+    assert!(!ignore.is_ignored(&Npm, "5.0.0").unwrap());
+    assert!(ignore.is_ignored(&Npm, "5.1.0").unwrap());
+    assert!(ignore.is_ignored(&Npm, "latest").is_err());
+    ignore.versions = Some(vec!["[4.0,".to_owned()]);
+    assert!(ignore.is_ignored(&Maven, "4.0").is_err());
+}
+
+#[test]
+fn test_v2_branch_name() {
+    let update: v2::Update = "package-ecosystem: cargo
+directory: /
+schedule:
+  interval: weekly
+"
+    .parse()
+    .unwrap();
+    let serde = v2::Candidate::new("serde", "1.0.199", "1.0.200");
+    let tokio = v2::Candidate::new("tokio", "1.36.0", "1.37.0");
+    assert_eq!(update.branch_name(&serde), "dependabot/cargo/serde-1.0.200");
+    assert_eq!(
+        update.group_branch_name("rust-deps", &[serde.clone(), tokio.clone()]),
+        "dependabot/cargo/rust-deps-febc0c43ac"
+    );
+    // The digest doesn't depend on the order of the dependencies.
+    assert_eq!(
+        update.group_branch_name("rust-deps", &[tokio, serde.clone()]),
+        "dependabot/cargo/rust-deps-febc0c43ac"
+    );
+    assert_eq!(update.group_branch_name("rust-deps", &[]), "dependabot/cargo/rust-deps-d41d8cd98f");
+
+    let mut candidate = serde;
+    candidate.directory = Some("/crates/a".into());
+    assert_eq!(update.branch_name(&candidate), "dependabot/cargo/crates/a/serde-1.0.200");
+
+    let update: v2::Update = "package-ecosystem: npm
+directory: /frontend app
+target-branch: develop
+pull-request-branch-name:
+  separator: '-'
+schedule:
+  interval: weekly
+"
+    .parse()
+    .unwrap();
+    assert_eq!(
+        update.branch_name(&v2::Candidate::new("@types/node", "20.0.0", "20.1.0")),
+        "dependabot-npm_and_yarn-frontend-app-develop-types-node-20.1.0"
+    );
+
+    let update: v2::Update = "package-ecosystem: maven
+directory: /
+schedule:
+  interval: weekly
+"
+    .parse()
+    .unwrap();
+    assert_eq!(
+        update.branch_name(&v2::Candidate::new("org.slf4j:slf4j-api", "2.0.12", "2.0.13")),
+        "dependabot/maven/org.slf4j-slf4j-api-2.0.13"
+    );
+
+    let update: v2::Update = "package-ecosystem: github-actions
+directory: /.github
+schedule:
+  interval: weekly
+"
+    .parse()
+    .unwrap();
+    assert_eq!(
+        update.branch_name(&v2::Candidate::new(
+            "actions/checkout",
+            "v4.1.0",
+            "b4ffde65f46336ab88eb53be808477a3936bae11"
+        )),
+        "dependabot/github_actions/dot-github/actions/checkout-b4ffde6"
+    );
+    // Slashes are replaced with the separator after sanitizing, so `/.` has
+    // already been replaced with `/dot-`.
+    let update: v2::Update = "package-ecosystem: github-actions
+directory: /.github
+pull-request-branch-name:
+  separator: '_'
+schedule:
+  interval: weekly
+"
+    .parse()
+    .unwrap();
+    assert_eq!(
+        update.branch_name(&v2::Candidate::new("actions/checkout", "v4.1.0", "v4.2.0")),
+        "dependabot_github_actions_dot-github_actions_checkout-v4.2.0"
+    );
+}
+
+#[test]
+fn test_v2_update_type_classify() {
+    use v2::{
+        PackageEcosystem::{Bundler, Cargo, Npm, Nuget, Pip},
+        UpdateType::{SemverMajor, SemverMinor, SemverPatch},
+    };
+
+    let classify = |ecosystem: v2::PackageEcosystem, old: &str, new: &str| {
+        v2::UpdateType::classify(&ecosystem, old, new).unwrap()
+    };
+
+    assert_eq!(classify(Npm, "1.2.3", "2.0.0"), Some(SemverMajor));
+    assert_eq!(classify(Npm, "1.2.3", "1.3.0"), Some(SemverMinor));
+    assert_eq!(classify(Npm, "1.2.3", "1.2.4"), Some(SemverPatch));
+    assert_eq!(classify(Npm, "1.2.3", "1.2.3"), None);
+    assert_eq!(classify(Npm, "1.2", "1.2.0"), None);
+    assert_eq!(classify(Npm, "1.0.0-rc.1", "1.0.0"), Some(SemverPatch));
+    assert_eq!(classify(Npm, "0.2.3", "0.3.0"), Some(SemverMinor));
+    assert_eq!(classify(Npm, "v1.9.0", "v1.10.0"), Some(SemverMinor));
+    assert_eq!(classify(Bundler, "7.0.8", "7.1.0.rc1"), Some(SemverMinor));
+    assert_eq!(classify(Pip, "2.31.0", "2.31.0.post1"), Some(SemverPatch));
+
+    // Cargo's 0.x compatibility rules.
+    assert_eq!(classify(Cargo, "0.2.3", "0.3.0"), Some(SemverMajor));
+    assert_eq!(classify(Cargo, "0.2.3", "0.2.4"), Some(SemverMinor));
+    assert_eq!(classify(Cargo, "0.0.3", "0.0.4"), Some(SemverMajor));
+    assert_eq!(classify(Cargo, "0.0.0", "0.0.1"), Some(SemverMajor));
+    assert_eq!(classify(Cargo, "0.9.0", "1.0.0"), Some(SemverMajor));
+    assert_eq!(classify(Cargo, "1.2.3", "1.2.4"), Some(SemverPatch));
+
+    // Four-part NuGet versions.
+    assert_eq!(classify(Nuget, "4.7.2.1", "4.7.2.2"), Some(SemverPatch));
+    assert_eq!(classify(Nuget, "4.7.2.1", "4.8.0.0"), Some(SemverMinor));
+
+    // Calendar versions are classified as any other version, as in Dependabot.
+    assert_eq!(classify(Pip, "2023.12.1", "2024.1.0"), Some(SemverMajor));
+    assert_eq!(classify(Pip, "2024.1.0", "2024.2.0"), Some(SemverMinor));
+    assert_eq!(classify(Npm, "1000.0.0", "2000.0.0"), Some(SemverMajor));
+
+    // PEP 440 epochs.
+    assert_eq!(classify(Pip, "1.0", "1!0.5"), Some(SemverMajor));
+    assert_eq!(classify(Pip, "1!1.0", "1!1.1"), Some(SemverMinor));
+
+    assert!(v2::UpdateType::classify(&Npm, "1.0.0", "latest").is_err());
+}
+
+#[test]
+fn test_v2_evaluate() {
+    let update: v2::Update = "package-ecosystem: npm
+directory: /
+schedule:
+  interval: daily
+allow:
+  - dependency-type: production
+  - dependency-name: '@types/*'
+ignore:
+  - dependency-name: left-pad
+  - dependency-name: express
+    versions: ['5.x']
+  - dependency-name: '*'
+    update-types: [version-update:semver-major]
+versioning-strategy: lockfile-only
+open-pull-requests-limit: 3
+"
+    .parse()
+    .unwrap();
+    let evaluate = |update: &v2::Update, candidate: &v2::Candidate| {
+        let decision = update.evaluate(candidate).unwrap();
+        (decision.update, decision.reason, decision.to_string())
+    };
+
+    let mut candidate = v2::Candidate::new("react", "18.2.0", "18.3.1");
+    assert_eq!(
+        evaluate(&update, &candidate),
+        (
+            true,
+            v2::DecisionReason::Update,
+            "allow[0]: The dependency 'react' would be updated from 18.2.0 to 18.3.1".to_owned()
+        )
+    );
+    candidate.development = true;
+    assert_eq!(
+        evaluate(&update, &candidate),
+        (
+            false,
+            v2::DecisionReason::NotAllowed,
+            "allow: The dependency 'react' doesn't match any entry of 'allow'".to_owned()
+        )
+    );
+    candidate.name = "@types/react".to_owned();
+    assert_eq!(
+        evaluate(&update, &candidate).2,
+        "allow[1]: The dependency '@types/react' would be updated from 18.2.0 to 18.3.1"
+    );
+
+    let candidate = v2::Candidate::new("react", "18.3.1", "18.3.1");
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::NotNewer);
+    let candidate = v2::Candidate::new("left-pad", "1.0.0", "1.0.1");
+    assert_eq!(
+        evaluate(&update, &candidate).2,
+        "ignore[0]: All updates of the dependency 'left-pad' are ignored"
+    );
+    let candidate = v2::Candidate::new("express", "4.18.0", "5.0.1");
+    assert_eq!(
+        evaluate(&update, &candidate).2,
+        "ignore[1].versions[0]: The version 5.0.1 matches the ignored versions '5.x'"
+    );
+    let candidate = v2::Candidate::new("react", "17.0.2", "18.0.0");
+    assert_eq!(
+        evaluate(&update, &candidate),
+        (
+            false,
+            v2::DecisionReason::Ignored,
+            "ignore[2].update-types[0]: The update from 17.0.2 to 18.0.0 is a 'version-update:semver-major' update, which is ignored".to_owned()
+        )
+    );
+
+    let mut candidate = v2::Candidate::new("react", "18.2.0", "18.3.1");
+    candidate.requirement = Some("~18.2.0".to_owned());
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::LockfileOnly);
+    candidate.requirement = Some("^18.2.0".to_owned());
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::Update);
+    candidate.open_pull_requests = 3;
+    assert_eq!(
+        evaluate(&update, &candidate),
+        (
+            false,
+            v2::DecisionReason::PullRequestLimit,
+            "open-pull-requests-limit: 3 pull requests are already open, which reaches 'open-pull-requests-limit' of 3".to_owned()
+        )
+    );
+
+    // Defaults: only direct dependencies, and up to 5 pull requests.
+    let mut update =
+        v2::Update::new(v2::PackageEcosystem::Cargo, "/", v2::Schedule::new(v2::Interval::Daily));
+    let mut candidate = v2::Candidate::new("serde", "1.0.0", "2.0.0");
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::Update);
+    assert_eq!(
+        evaluate(&update, &candidate).2,
+        "The dependency 'serde' would be updated from 1.0.0 to 2.0.0"
+    );
+    candidate.open_pull_requests = 5;
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::PullRequestLimit);
+    candidate.open_pull_requests = 0;
+    candidate.direct = false;
+    assert_eq!(evaluate(&update, &candidate).1, v2::DecisionReason::NotAllowed);
+    update.open_pull_requests_limit = Some(0);
+    candidate.direct = true;
+    assert_eq!(
+        evaluate(&update, &candidate).2,
+        "open-pull-requests-limit: Version updates are disabled because 'open-pull-requests-limit' is 0"
+    );
+    assert!(update.evaluate(&v2::Candidate::new("serde", "1.0.0", "latest")).is_err());
+}
+
+#[test]
+fn test_v2_cron_expression() {
+    let cron: v2::CronExpression = "*/15 9-17 1,15 jan-mar/2 MON-fri".parse().unwrap();
+    assert_eq!(cron.to_string(), "*/15 9-17 1,15 jan-mar/2 MON-fri");
+    assert_eq!(cron.minutes().collect::<Vec<_>>(), [0, 15, 30, 45]);
+    assert_eq!(cron.hours().collect::<Vec<_>>(), [9, 10, 11, 12, 13, 14, 15, 16, 17]);
+    assert_eq!(cron.days_of_month().collect::<Vec<_>>(), [1, 15]);
+    assert_eq!(cron.months().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(cron.days_of_week().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+    let cron: v2::CronExpression = "0 0 * * 7".parse().unwrap();
+    assert_eq!(cron.days_of_week().collect::<Vec<_>>(), [0]);
+    let cron: v2::CronExpression = "5/20 * * * *".parse().unwrap();
+    assert_eq!(cron.minutes().collect::<Vec<_>>(), [5, 25, 45]);
+
+    for (s, e) in [
+        ("* * * *", "expected 5 fields"),
+        ("60 * * * *", "minute value \"60\" is out of range 0-59"),
+        ("* 24 * * *", "hour value \"24\" is out of range 0-23"),
+        ("* * 0 * *", "day of month value \"0\" is out of range 1-31"),
+        ("* * * foo *", "invalid month value \"foo\""),
+        ("* * * * 8", "day of week value \"8\" is out of range 0-7"),
+        ("*/0 * * * *", "invalid minute step \"0\""),
+        ("5-1 * * * *", "invalid minute range \"5-1\""),
+    ] {
+        let err = s.parse::<v2::CronExpression>().unwrap_err().to_string();
+        assert!(err.contains(e), "{err}");
+    }
+
+    let schedule: v2::Schedule = "interval: cron\ncronjob: '0 9 * * *'".parse().unwrap();
+    assert_eq!(schedule.interval, v2::Interval::Cron);
+    assert_eq!(schedule.cronjob.unwrap().as_str(), "0 9 * * *");
+    let err = "interval: cron\ncronjob: '0 9 * *'".parse::<v2::Schedule>().unwrap_err();
+    assert!(
+        err.to_string().starts_with(
+            "The property 'schedule/cronjob' value \"0 9 * *\" did not match a cron expression"
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_v2_schedule_time_and_timezone() {
+    let time: v2::ScheduleTime = "09:05".parse().unwrap();
+    assert_eq!((time.hour(), time.minute()), (9, 5));
+    assert_eq!(time.to_string(), "09:05");
+    assert_eq!(v2::ScheduleTime::new(23, 59).unwrap().to_string(), "23:59");
+    assert!(v2::ScheduleTime::new(24, 0).is_none());
+    assert!(v2::ScheduleTime::new(0, 60).is_none());
+    for s in ["9:00", "09:0", "24:00", "12:60", "+1:00", "0900", "09:00:00", ""] {
+        let err = s.parse::<v2::ScheduleTime>().unwrap_err().to_string();
+        assert_eq!(
+            err,
+            format!(
+                "The property 'schedule/time' value \"{s}\" \
+                 did not match the regex '^([01][0-9]|2[0-3]):[0-5][0-9]$'"
+            )
+        );
+    }
+
+    for s in ["Asia/Tokyo", "America/New_York", "Etc/UTC", "UTC"] {
+        assert_eq!(s.parse::<v2::Timezone>().unwrap().as_str(), s);
+    }
+    for s in
+        ["asia/tokyo", "Asia/Tokio", "JST", "", "EST", "EST5EDT", "GMT+0", "US/Eastern", "Factory"]
+    {
+        let err = s.parse::<v2::Timezone>().unwrap_err().to_string();
+        assert_eq!(
+            err,
+            format!(
+                "The property 'schedule/timezone' value \"{s}\" \
+                 did not match any time zone in the IANA time zone database"
+            )
+        );
+    }
+
+    let schedule: v2::Schedule =
+        "interval: daily\ntime: '17:30'\ntimezone: Europe/Berlin".parse().unwrap();
+    assert_eq!(schedule.time, v2::ScheduleTime::new(17, 30));
+    assert_eq!(schedule.timezone.as_ref().unwrap().as_str(), "Europe/Berlin");
+    assert_eq!(
+        serde_yaml::to_string(&schedule).unwrap(),
+        "interval: daily\ntime: 17:30\ntimezone: Europe/Berlin\n"
+    );
+    assert!("interval: daily\ntime: '9:00'".parse::<v2::Schedule>().is_err());
+}
+
+#[test]
+fn test_v2_schedule_next_runs() {
+    fn unix(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+    #[track_caller]
+    fn next_runs(schedule: &str, after: u64, n: usize) -> Vec<u64> {
+        let schedule: v2::Schedule = schedule.parse().unwrap();
+        let runs = schedule.next_runs(unix(after), n);
+        runs.iter().map(|t| t.duration_since(UNIX_EPOCH).unwrap().as_secs()).collect()
+    }
+
+    // Daily runs on weekdays only. 2024-01-05 is a Friday.
+    assert_eq!(next_runs("interval: daily\ntime: '09:00'", 1_704_448_800, 3), [
+        1_704_704_400,
+        1_704_790_800,
+        1_704_877_200,
+    ]);
+    // Weekly defaults to Monday. 2024-01-01 is a Monday, and runs strictly after `after`.
+    assert_eq!(next_runs("interval: weekly", 1_704_067_200, 2), [1_704_672_000, 1_705_276_800]);
+    assert_eq!(
+        next_runs(
+            "interval: weekly\nday: sunday\ntime: '17:30'\ntimezone: Asia/Tokyo",
+            1_704_067_200,
+            2
+        ),
+        [1_704_616_200, 1_705_221_000]
+    );
+    assert_eq!(next_runs("interval: monthly", 1_705_276_800, 2), [1_706_745_600, 1_709_251_200]);
+    assert_eq!(next_runs("interval: quarterly", 1_704_067_201, 4), [
+        1_711_929_600,
+        1_719_792_000,
+        1_727_740_800,
+        1_735_689_600,
+    ]);
+    assert_eq!(next_runs("interval: semiannually", 1_704_067_201, 1), [1_719_792_000]);
+    assert_eq!(next_runs("interval: yearly", 1_704_067_201, 1), [1_735_689_600]);
+
+    // Cron expressions are evaluated in the time zone, across DST transitions.
+    let cron = "interval: cron\ncronjob: '30 9 * * 1-5'\ntimezone: America/New_York";
+    assert_eq!(next_runs(cron, 1_709_856_000, 2), [1_709_908_200, 1_710_163_800]);
+    // 02:30 does not exist on 2024-03-10 in New York, so it is shifted forward to 03:30.
+    let cron = "interval: cron\ncronjob: '30 2 * * *'\ntimezone: America/New_York";
+    assert_eq!(next_runs(cron, 1_709_942_400, 3), [1_709_969_400, 1_710_055_800, 1_710_138_600]);
+    // 01:30 occurs twice on 2024-11-03 in New York; only the earlier one is used.
+    let cron = "interval: cron\ncronjob: '30 1 3 11 *'\ntimezone: America/New_York";
+    assert_eq!(next_runs(cron, 1_704_067_200, 1), [1_730_611_800]);
+
+    assert_eq!(next_runs("interval: cron", 0, 1), []);
+    assert_eq!(next_runs("interval: cron\ncronjob: '0 0 30 2 *'", 0, 1), []);
+    assert_eq!(next_runs("interval: daily", 0, 0), []);
+}
+
 #[test]
 fn test_v1() {
     let v1_test_data = &*fs::read_to_string(fixtures_dir().join("v1.yml")).unwrap();
@@ -81,6 +893,971 @@ fn test_v1() {
     }
 }
 
+#[test]
+fn test_v1_to_v2() {
+    let s = "version: 1
+update_configs:
+  - package_manager: ruby:bundler
+    directory: /
+    update_schedule: live
+    target_branch: develop
+    default_reviewers: [octocat]
+    default_labels: [dependencies]
+    default_milestone: 4
+    allowed_updates:
+      - match:
+          dependency_type: production
+      - match:
+          update_type: security
+    ignored_updates:
+      - match:
+          dependency_name: express
+          version_requirement: 4.x
+    automerged_updates:
+      - match:
+          dependency_type: all
+          update_type: semver:patch
+    version_requirement_updates: increase_versions_if_necessary
+    commit_message:
+      prefix: fix
+      include_scope: true
+  - package_manager: go:dep
+    directory: /
+    update_schedule: daily
+";
+    let v1: v1::Dependabot = s.parse().unwrap();
+    let e = v2::Dependabot::try_from(v1.clone()).unwrap_err();
+    assert!(
+        e.to_string().starts_with(
+            "the configuration cannot be converted to version 2 without loss: update_configs[0].update_schedule: "
+        ),
+        "{e}"
+    );
+    let (config, lossy) = v2::Dependabot::from_v1(v1);
+    assert_eq!(e.lossy, lossy);
+    assert_eq!(lossy.iter().map(|item| (item.code, &*item.path)).collect::<Vec<_>>(), [
+        (v2::LossyItemCode::LiveSchedule, "update_configs[0].update_schedule"),
+        (
+            v2::LossyItemCode::SecurityOnlyUpdates,
+            "update_configs[0].allowed_updates[1].match.update_type"
+        ),
+        (v2::LossyItemCode::AutomergedUpdates, "update_configs[0].automerged_updates"),
+        (v2::LossyItemCode::GoDep, "update_configs[1].package_manager"),
+    ]);
+    assert_eq!(
+        config.to_string(),
+        "version: 2
+updates:
+  - package-ecosystem: bundler
+    directory: /
+    schedule:
+      interval: daily
+    allow:
+      - dependency-type: production
+    commit-message:
+      prefix: fix
+      include: scope
+    ignore:
+      - dependency-name: express
+        versions:
+          - 4.x
+    labels:
+      - dependencies
+    milestone: 4
+    reviewers:
+      - octocat
+    target-branch: develop
+    versioning-strategy: increase-if-necessary
+"
+    );
+
+    let v1_test_data = &*fs::read_to_string(fixtures_dir().join("v1.yml")).unwrap();
+    for case in from_str::<v1::Dependabot>(v1_test_data) {
+        let (config, _) = v2::Dependabot::from_v1(case);
+        assert_eq!(config.validate(), []);
+        config.to_string().parse::<v2::Dependabot>().unwrap();
+    }
+    let v1: v1::Dependabot =
+        "version: 1\nupdate_configs:\n  - package_manager: rust:cargo\n    directory: /\n    update_schedule: weekly\n"
+            .parse()
+            .unwrap();
+    let config = v2::Dependabot::try_from(v1).unwrap();
+    assert_eq!(config.updates[0].package_ecosystem, v2::PackageEcosystem::Cargo);
+    assert_eq!(config.updates[0].schedule.interval, v2::Interval::Weekly);
+
+    // Only security updates are allowed: version updates are disabled.
+    let v1: v1::Dependabot = "version: 1
+update_configs:
+  - package_manager: javascript
+    directory: /
+    update_schedule: daily
+    allowed_updates:
+      - match:
+          update_type: security
+      - match:
+          dependency_name: express
+          update_type: security
+"
+    .parse()
+    .unwrap();
+    let (config, lossy) = v2::Dependabot::from_v1(v1);
+    assert!(config.updates[0].allow.is_none());
+    assert_eq!(config.updates[0].open_pull_requests_limit, Some(0));
+    assert_eq!(lossy.len(), 2);
+    assert!(lossy.iter().all(|item| item.code == v2::LossyItemCode::SecurityOnlyUpdates));
+    assert!(lossy[0].message.contains("'open-pull-requests-limit' is set to 0"), "{}", lossy[0]);
+    let candidate = v2::Candidate::new("express", "4.0.0", "4.1.0");
+    assert_eq!(
+        config.updates[0].evaluate(&candidate).unwrap().reason,
+        v2::DecisionReason::PullRequestLimit
+    );
+}
+
+#[test]
+fn test_v1_automerge_workflow() {
+    let config: v1::Dependabot = "version: 1
+update_configs:
+  - package_manager: javascript
+    directory: /
+    update_schedule: daily
+    automerged_updates:
+      - match:
+          dependency_type: development
+          update_type: semver:minor
+      - match:
+          dependency_name: '@types/*'
+          update_type: in_range
+  - package_manager: ruby:bundler
+    directory: /app/
+    update_schedule: daily
+    automerged_updates:
+      - match:
+          dependency_name: rails
+          update_type: security:patch
+"
+    .parse()
+    .unwrap();
+    let workflow = v1::automerge_workflow(&config);
+    assert_eq!(
+        workflow,
+        r#"name: Dependabot auto-merge
+
+on: pull_request
+
+permissions:
+  contents: write
+  pull-requests: write
+
+jobs:
+  automerge:
+    runs-on: ubuntu-latest
+    if: github.event.pull_request.user.login == 'dependabot[bot]'
+    steps:
+      - id: metadata
+        uses: dependabot/fetch-metadata@v2
+        with:
+          github-token: ${{ secrets.GITHUB_TOKEN }}
+          alert-lookup: true
+      - if: "(steps.metadata.outputs.package-ecosystem == 'npm_and_yarn' && steps.metadata.outputs.directory == '/' && steps.metadata.outputs.dependency-type == 'direct:development' && steps.metadata.outputs.update-type == 'version-update:semver-minor') || (steps.metadata.outputs.package-ecosystem == 'npm_and_yarn' && steps.metadata.outputs.directory == '/' && !contains(steps.metadata.outputs.dependency-names, ',') && startsWith(steps.metadata.outputs.dependency-names, '@types/') && (steps.metadata.outputs.update-type == 'version-update:semver-minor' || steps.metadata.outputs.update-type == 'version-update:semver-patch')) || (steps.metadata.outputs.package-ecosystem == 'bundler' && steps.metadata.outputs.directory == '/app' && steps.metadata.outputs.dependency-names == 'rails' && steps.metadata.outputs.update-type == 'version-update:semver-patch' && steps.metadata.outputs.ghsa-id != '')"
+        run: gh pr merge --auto --merge "$PR_URL"
+        env:
+          PR_URL: ${{ github.event.pull_request.html_url }}
+          GH_TOKEN: ${{ secrets.GITHUB_TOKEN }}
+"#
+    );
+
+    let condition = |matches: &str| {
+        let config: v1::Dependabot = format!(
+            "version: 1\nupdate_configs:\n  - package_manager: go:dep\n    directory: /\n    update_schedule: daily\n    automerged_updates: {matches}\n"
+        )
+        .parse()
+        .unwrap();
+        let workflow: serde_yaml::Value =
+            serde_yaml::from_str(&v1::automerge_workflow(&config)).unwrap();
+        let condition = workflow["jobs"]["automerge"]["steps"][1]["if"].as_str().unwrap();
+        condition
+            .strip_prefix(
+                "steps.metadata.outputs.package-ecosystem == 'dep' && steps.metadata.outputs.directory == '/'",
+            )
+            .unwrap_or(condition)
+            .to_owned()
+    };
+    assert_eq!(condition("[]"), "false");
+    assert_eq!(condition("[{match: {dependency_type: all, update_type: all}}]"), "");
+    assert_eq!(
+        condition("[{match: {dependency_name: \"it's \\\"*-x*\\\"\"}}]"),
+        r#" && !contains(steps.metadata.outputs.dependency-names, ',') && startsWith(steps.metadata.outputs.dependency-names, 'it''s "') && contains(steps.metadata.outputs.dependency-names, '-x') && endsWith(steps.metadata.outputs.dependency-names, '"')"#
+    );
+
+    let v1_test_data = &*fs::read_to_string(fixtures_dir().join("v1.yml")).unwrap();
+    for case in from_str::<v1::Dependabot>(v1_test_data) {
+        serde_yaml::from_str::<serde_yaml::Value>(&v1::automerge_workflow(&case)).unwrap();
+    }
+}
+
+#[test]
+fn test_v2_parse_mode() {
+    let s = "version: 2
+x-top: 1
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+      x-schedule: [a, b]
+    labls:
+      - dependencies
+";
+    // Lenient mode keeps unknown properties.
+    let config = v2::Dependabot::parse(s, v2::ParseMode::Lenient).unwrap();
+    assert_eq!(config.extra["x-top"], Value::Integer(1));
+    assert_eq!(
+        config.updates[0].schedule.extra["x-schedule"],
+        Value::Sequence(vec![Value::String("a".to_owned()), Value::String("b".to_owned()),])
+    );
+    assert!(config.updates[0].extra.contains_key("labls"));
+    assert_eq!(
+        serde_yaml::from_str::<serde_yaml::Value>(&config.to_string()).unwrap(),
+        serde_yaml::from_str::<serde_yaml::Value>(s).unwrap()
+    );
+    assert_eq!(s.parse::<v2::Dependabot>().unwrap().to_string(), config.to_string());
+    // Strict mode rejects unknown properties.
+    let e = v2::Dependabot::parse(s, v2::ParseMode::Strict).unwrap_err();
+    assert!(e.is_unknown_field());
+    assert!(e.to_string().starts_with("unknown field `x-top`, expected one of `version`"), "{e}");
+    let s = &s.replace("x-top: 1\n", "");
+    let e = v2::Dependabot::parse(s, v2::ParseMode::Strict).unwrap_err();
+    assert!(e.to_string().starts_with("updates[0]: unknown field `labls`"), "{e}");
+    assert!(e.to_string().ends_with("(did you mean `labels`?) at line 3 column 5"), "{e}");
+    let s = &s.replace("labls", "labels");
+    let e = v2::Dependabot::parse(s, v2::ParseMode::Strict).unwrap_err();
+    assert!(e.to_string().starts_with("updates[0].schedule: unknown field `x-schedule`"), "{e}");
+    let s = &s.replace("      x-schedule: [a, b]\n", "    target_branch: main\n");
+    let e = v2::Dependabot::parse(s, v2::ParseMode::Strict).unwrap_err();
+    assert!(
+        e.to_string().contains(
+            "(`target_branch` is a version 1 property; use `target-branch` in version 2)"
+        ),
+        "{e}"
+    );
+    let s = &s.replace("    target_branch: main\n", "");
+    v2::Dependabot::parse(s, v2::ParseMode::Strict).unwrap();
+
+    // Unknown properties cannot be represented by `TypedRegistry`.
+    let e = "type: git\nurl: https://github.com\nusername: x\npassword: y\nfoo: z\n"
+        .parse::<v2::TypedRegistry>()
+        .unwrap_err();
+    assert_eq!(e.to_string(), "registry of type 'git' does not support the 'foo' property");
+
+    let v2_test_data = &*fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
+    for case in from_str::<serde_yaml::Value>(v2_test_data) {
+        let s = serde_yaml::to_string(&case).unwrap();
+        v2::Dependabot::parse(&s, v2::ParseMode::Strict).unwrap();
+    }
+
+    // Unknown properties can have any value.
+    let s = "version: 2
+updates: []
+x-key: {1: a}
+x-tag: !tag bar
+x-tagged:
+  - !tag
+    a: 1
+x-u64: 18446744073709551615
+";
+    let config = s.parse::<v2::Dependabot>().unwrap();
+    assert_eq!(
+        config.extra["x-key"],
+        Value::Mapping([(Value::Integer(1), Value::String("a".to_owned()))].into_iter().collect())
+    );
+    assert_eq!(
+        config.extra["x-tag"],
+        Value::Tagged("tag".to_owned(), Box::new(Value::String("bar".to_owned())))
+    );
+    assert_eq!(config.extra["x-u64"], Value::Integer(u64::MAX.into()));
+    assert_eq!(config.to_string(), s.replace(" {1: a}", "\n  1: a"));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_v2_document() {
+    let value = |update: &v2::Update| serde_yaml::to_value(update).unwrap();
+
+    // Editing a property only changes that property.
+    let v2_test_data = fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
+    let dependabot_yml =
+        fs::read_to_string(fixtures_dir().join("../../.github/dependabot.yml")).unwrap();
+    for s in v2_test_data.split("---\n").skip(1).chain([&*dependabot_yml]) {
+        let doc: v2::Document = s.parse().unwrap();
+        assert_eq!(doc.to_string(), s);
+        for i in 0..doc.config().updates.len() {
+            let mut edited = doc.clone();
+            let mut update = edited.update_mut(i).unwrap();
+            update.set_milestone(Some(42)).unwrap();
+            let mut expected = doc.config().updates[i].clone();
+            expected.milestone = Some(42);
+            assert_eq!(value(update.get()), value(&expected));
+            for (a, b) in edited.config().updates.iter().zip(&doc.config().updates).skip(i + 1) {
+                assert_eq!(value(a), value(b));
+            }
+            if doc.config().updates[i].milestone.is_none() {
+                edited.update_mut(i).unwrap().set_milestone(None).unwrap();
+                assert_eq!(edited.to_string(), s);
+            }
+        }
+    }
+
+    let s = "\
+# Dependabot configuration
+version: 2
+updates:
+  - package-ecosystem: \"cargo\" # Rust
+    directory: /
+    schedule:
+      interval: weekly # on Monday
+
+    labels: [dependencies] # default labels
+
+  # GitHub Actions
+  - package-ecosystem: github-actions
+    directory: /
+    schedule:
+      interval: weekly
+";
+    let mut doc: v2::Document = s.parse().unwrap();
+    let mut update = doc.update_mut(0).unwrap();
+    update.set_package_ecosystem(v2::PackageEcosystem::Npm).unwrap();
+    update.set_labels(None).unwrap();
+    update.set_open_pull_requests_limit(Some(5)).unwrap();
+    update.set_reviewers(Some(vec!["octocat".to_owned()])).unwrap();
+    let mut update = doc.update_mut(1).unwrap();
+    let mut schedule = update.get().schedule.clone();
+    schedule.day = Some(v2::Day::Friday);
+    update.set_schedule(schedule).unwrap();
+    update.set_directory(None).unwrap();
+    update
+        .set_directories(Some(vec![v2::PathPattern::new("/a"), v2::PathPattern::new("/b")]))
+        .unwrap();
+    assert_eq!(
+        doc.to_string(),
+        "\
+# Dependabot configuration
+version: 2
+updates:
+  - package-ecosystem: npm # Rust
+    directory: /
+    schedule:
+      interval: weekly # on Monday
+    open-pull-requests-limit: 5
+    reviewers:
+      - octocat
+
+  # GitHub Actions
+  - package-ecosystem: github-actions
+    directories:
+      - /a
+      - /b
+    schedule:
+      interval: weekly
+      day: friday
+"
+    );
+
+    // The first property of an update can be removed and inserted.
+    let mut update = doc.update_mut(1).unwrap();
+    update.set_package_ecosystem(v2::PackageEcosystem::Cargo).unwrap();
+    let mut doc2 = doc.clone();
+    let mut update = doc2.update_mut(1).unwrap();
+    update.set_directories(None).unwrap();
+    update.set_directory(Some("/".to_owned())).unwrap();
+    assert!(
+        doc2.to_string().ends_with(
+            "\
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: weekly
+      day: friday
+"
+        ),
+        "{doc2}"
+    );
+
+    // Adding and removing updates.
+    let removed = doc.remove_update(0).unwrap();
+    assert_eq!(removed.package_ecosystem, v2::PackageEcosystem::Npm);
+    doc.push_update(&v2::Update::new(
+        v2::PackageEcosystem::Docker,
+        "/",
+        v2::Schedule::new(v2::Interval::Daily),
+    ))
+    .unwrap();
+    doc.set_enable_beta_ecosystems(Some(true)).unwrap();
+    assert_eq!(
+        doc.to_string(),
+        "\
+# Dependabot configuration
+version: 2
+enable-beta-ecosystems: true
+updates:
+  # GitHub Actions
+  - package-ecosystem: cargo
+    directories:
+      - /a
+      - /b
+    schedule:
+      interval: weekly
+      day: friday
+  - package-ecosystem: docker
+    directory: /
+    schedule:
+      interval: daily
+"
+    );
+    assert_eq!(doc.config().updates.len(), 2);
+
+    // Inserted lines follow the line breaks and the indentation of the document.
+    let s = "\
+version: 2
+updates:
+    -   package-ecosystem: cargo
+        directory: /
+        schedule:
+            interval: weekly
+        ignore:
+        -   dependency-name: serde
+"
+    .replace('\n', "\r\n");
+    let mut doc: v2::Document = s.parse().unwrap();
+    let mut update = doc.update_mut(0).unwrap();
+    update.set_labels(Some(vec!["a".to_owned()])).unwrap();
+    let mut schedule = update.get().schedule.clone();
+    schedule.day = Some(v2::Day::Friday);
+    update.set_schedule(schedule).unwrap();
+    let mut update =
+        v2::Update::new(v2::PackageEcosystem::Npm, "/", v2::Schedule::new(v2::Interval::Daily));
+    update.labels = Some(vec!["b".to_owned()]);
+    doc.push_update(&update).unwrap();
+    assert_eq!(
+        doc.to_string(),
+        "\
+version: 2
+updates:
+    -   package-ecosystem: cargo
+        directory: /
+        schedule:
+            interval: weekly
+            day: friday
+        ignore:
+        -   dependency-name: serde
+        labels:
+        - a
+    - package-ecosystem: npm
+      directory: /
+      schedule:
+          interval: daily
+      labels:
+      - b
+"
+        .replace('\n', "\r\n")
+    );
+    doc.remove_update(1).unwrap();
+    doc.update_mut(0).unwrap().set_labels(None).unwrap();
+    assert_eq!(
+        doc.to_string(),
+        s.replace("interval: weekly\r\n", "interval: weekly\r\n            day: friday\r\n")
+    );
+
+    // Updates in flow style cannot be edited, and unsupported syntax is rejected.
+    let mut doc: v2::Document =
+        "version: 2\nupdates:\n  - {package-ecosystem: cargo, directory: /, schedule: {interval: daily}}\n"
+            .parse()
+            .unwrap();
+    let e = doc.update_mut(0).unwrap().set_vendor(Some(true)).unwrap_err();
+    assert_eq!(e.to_string(), "updates[0] cannot be edited because it is not a block mapping");
+    doc.push_update(&v2::Update::new(
+        v2::PackageEcosystem::Npm,
+        "/",
+        v2::Schedule::new(v2::Interval::Daily),
+    ))
+    .unwrap();
+    assert_eq!(doc.config().updates.len(), 2);
+    let e = "version: 2\nupdates:\n  - &a\n    package-ecosystem: cargo\n    directory: /\n    schedule: {interval: daily}\n"
+        .parse::<v2::Document>()
+        .unwrap_err();
+    assert!(e.to_string().starts_with("unsupported YAML: anchors"), "{e}");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_v2_format() {
+    let v2_test_data = fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
+    let dependabot_yml =
+        fs::read_to_string(fixtures_dir().join("../../.github/dependabot.yml")).unwrap();
+    for s in v2_test_data.split("---\n").skip(1).chain([&*dependabot_yml]) {
+        let formatted = v2::format(s).unwrap();
+        assert_eq!(v2::format(&formatted).unwrap(), formatted);
+        let mut a = serde_yaml::from_str::<serde_yaml::Value>(s).unwrap();
+        let mut b = serde_yaml::from_str::<serde_yaml::Value>(&formatted).unwrap();
+        for v in [&mut a, &mut b] {
+            // Normalize the order of updates and the representation of scalars.
+            v["updates"]
+                .as_sequence_mut()
+                .unwrap()
+                .sort_by_key(|u| serde_yaml::to_string(u).unwrap());
+            *v = serde_yaml::from_str(&serde_yaml::to_string(v).unwrap()).unwrap();
+        }
+        assert_eq!(a, b, "{formatted}");
+    }
+    // ToString uses the same style.
+    assert_eq!(
+        v2::format(&dependabot_yml).unwrap(),
+        dependabot_yml.parse::<v2::Dependabot>().unwrap().to_string()
+    );
+
+    let s = "\
+updates:
+  - x-custom: 1
+    schedule:
+      time: \"09:00\"
+      interval: \"weekly\"
+    directory: \"/sub\"
+    package-ecosystem: \"cargo\"
+    commit-message:
+      prefix: ''
+    labels: ['yes', 'dependencies', '1_000', '-1_0.5', '0b1_0', '0x_FF', '1_0:30', '=', '<<', '1.2.3', '1_', '_1', '1.x']
+  - package-ecosystem: cargo
+    directories: ['/']
+    schedule: {interval: daily}
+    ignore:
+      - dependency-name: \"*\"
+        update-types: [version-update:semver-major]
+    reviewers: []
+version: 2
+";
+    assert_eq!(
+        v2::format(s).unwrap(),
+        "\
+version: 2
+updates:
+  - package-ecosystem: cargo
+    directories:
+      - /
+    schedule:
+      interval: daily
+    ignore:
+      - dependency-name: '*'
+        update-types:
+          - version-update:semver-major
+    reviewers: []
+  - package-ecosystem: cargo
+    directory: /sub
+    schedule:
+      interval: weekly
+      time: '09:00'
+    commit-message:
+      prefix: ''
+    labels:
+      - 'yes'
+      - dependencies
+      - '1_000'
+      - '-1_0.5'
+      - '0b1_0'
+      - '0x_FF'
+      - '1_0:30'
+      - '='
+      - '<<'
+      - 1.2.3
+      - '1_'
+      - _1
+      - 1.x
+    x-custom: 1
+"
+    );
+    assert!(v2::format("version: 2\nupdates: [\n").unwrap_err().is_syntax());
+}
+
+#[test]
+fn test_v2_other_variants() {
+    let s = "version: 2
+updates:
+  - package-ecosystem: new-ecosystem
+    directory: /
+    schedule:
+      interval: daily
+    allow:
+      - dependency-type: new-type
+    versioning-strategy: new-strategy
+registries:
+  new:
+    type: new-registry
+    url: https://example.com
+";
+    #[cfg(not(feature = "other-variants"))]
+    {
+        let e = s.parse::<v2::Dependabot>().unwrap_err();
+        assert!(e.is_invalid_enum_value(), "{e}");
+        assert!(e.to_string().starts_with("updates[0].package-ecosystem: unknown variant"), "{e}");
+    }
+    #[cfg(feature = "other-variants")]
+    {
+        let config: v2::Dependabot = s.parse().unwrap();
+        let update = &config.updates[0];
+        assert_eq!(
+            update.package_ecosystem,
+            v2::PackageEcosystem::Other("new-ecosystem".to_owned())
+        );
+        assert_eq!(update.package_ecosystem.to_string(), "new-ecosystem");
+        assert_eq!(
+            update.allow.as_ref().unwrap()[0].dependency_type,
+            Some(v2::DependencyType::Other("new-type".to_owned()))
+        );
+        assert_eq!(
+            update.versioning_strategy,
+            Some(v2::VersioningStrategy::Other("new-strategy".to_owned()))
+        );
+        let registry = &config.registries["new"];
+        assert_eq!(registry.type_, v2::RegistryType::Other("new-registry".to_owned()));
+        assert_eq!(
+            v2::TypedRegistry::try_from(registry.clone()).unwrap_err().to_string(),
+            "registry of type 'new-registry' is not supported"
+        );
+        assert_eq!(
+            serde_yaml::from_str::<serde_yaml::Value>(&config.to_string()).unwrap(),
+            serde_yaml::from_str::<serde_yaml::Value>(s).unwrap()
+        );
+        // Known values are not affected.
+        assert_eq!("cargo".parse::<v2::PackageEcosystem>().unwrap(), v2::PackageEcosystem::Cargo);
+    }
+}
+
+#[test]
+fn test_v2_parse_all_errors() {
+    // `type: svn` is accepted as `RegistryType::Other` with the `other-variants` feature.
+    #[cfg(not(feature = "other-variants"))]
+    {
+        let s = "version: 2
+registries:
+  npm:
+    type: npm-registry
+    url: https://registry.npmjs.org
+  git:
+    type: svn
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: hourly
+  - package-ecosystem: npm
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: pip
+    directory: /
+  - package-ecosystem: docker
+    directory: /
+    schedule:
+      interval: weekly
+      time: '25:00'
+";
+        let (config, errors) = v2::Dependabot::parse_all_errors(s);
+        let config = config.unwrap();
+        assert_eq!(config.registries.keys().collect::<Vec<_>>(), ["npm"]);
+        assert_eq!(config.updates.len(), 1);
+        assert_eq!(config.updates[0].package_ecosystem, v2::PackageEcosystem::Npm);
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| {
+                    let location = e.location().unwrap();
+                    (location.line(), location.path().map(str::to_owned))
+                })
+                .collect::<Vec<_>>(),
+            [
+                (7, Some("registries.git.type".to_owned())),
+                (12, Some("updates[0].schedule.interval".to_owned())),
+                (17, Some("updates[2]".to_owned())),
+                (22, Some("updates[3].schedule".to_owned())),
+            ]
+        );
+        assert!(errors[0].is_invalid_enum_value());
+        assert!(errors[2].is_missing_field());
+        assert!(errors[3].is_semantic());
+        let fixed = s
+            .replace("type: svn", "type: git")
+            .replace("interval: hourly", "interval: daily")
+            .replace("  - package-ecosystem: pip\n    directory: /\n", "")
+            .replace("time: '25:00'", "time: '23:00'");
+        assert_eq!(v2::Dependabot::parse_all_errors(&fixed).1.len(), 0);
+        // Same as the error reported by `FromStr`.
+        assert_eq!(
+            errors[1].to_string(),
+            s.replace("type: svn", "type: git").parse::<v2::Dependabot>().unwrap_err().to_string()
+        );
+    }
+
+    // Scalars are interpreted in the same way as `FromStr`.
+    let s = "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+    target-branch: 1.10
+";
+    let (config, errors) = v2::Dependabot::parse_all_errors(s);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(config.unwrap().updates[0].target_branch.as_deref(), Some("1.10"));
+
+    // Top-level errors
+    let (config, errors) = v2::Dependabot::parse_all_errors("version: 1\nupdates: [{}]\n");
+    assert!(config.is_none());
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].is_version_mismatch(), "{}", errors[0]);
+    assert!(errors[1].is_missing_field(), "{}", errors[1]);
+    let (config, errors) = v2::Dependabot::parse_all_errors("version: 2\nupdates: [\n");
+    assert!(config.is_none());
+    assert!(errors[0].is_syntax());
+
+    let v2_test_data = &*fs::read_to_string(fixtures_dir().join("v2.yml")).unwrap();
+    for case in from_str::<serde_yaml::Value>(v2_test_data) {
+        let s = serde_yaml::to_string(&case).unwrap();
+        let (config, errors) = v2::Dependabot::parse_all_errors(&s);
+        assert_eq!(errors.len(), 0);
+        assert_eq!(config.unwrap().to_string(), s.parse::<v2::Dependabot>().unwrap().to_string());
+    }
+}
+
+#[test]
+fn test_error_suggestion() {
+    let e = "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: weekely
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert!(e.is_invalid_enum_value());
+    assert!(
+        e.to_string().starts_with("updates[0].schedule.interval: unknown variant `weekely`"),
+        "{e}"
+    );
+    assert!(e.to_string().ends_with("(did you mean `weekly`?) at line 6 column 17"), "{e}");
+    #[cfg(not(feature = "other-variants"))]
+    {
+        let e = "Cargo".parse::<v2::PackageEcosystem>().unwrap_err();
+        assert!(e.to_string().ends_with("(did you mean `cargo`?)"), "{e}");
+    }
+    let e = "Daily".parse::<v2::Interval>().unwrap_err();
+    assert!(e.to_string().ends_with("(did you mean `daily`?)"), "{e}");
+    let e = "hourly".parse::<v2::Interval>().unwrap_err();
+    assert!(!e.to_string().contains("did you mean"), "{e}");
+
+    let e = "version: 2
+updates:
+  - package-ecosytem: cargo
+    directory: /
+    schedule:
+      interval: weekly
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert!(e.is_missing_field());
+    assert_eq!(
+        e.to_string(),
+        "updates[0]: missing field `package-ecosystem` (found `package-ecosytem`; did you mean `package-ecosystem`?) at line 3 column 5"
+    );
+
+    // Version 1 properties in a version 2 configuration
+    let e = "version: 2
+update_configs:
+  - package_manager: rust:cargo
+    directory: /
+    update_schedule: weekly
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "missing field `updates` (found version 1 property `update_configs`; use `updates` in version 2)"
+    );
+    let e = "version: 2
+updates:
+  - package_manager: cargo
+    directory: /
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert!(
+        e.to_string().contains(
+            "(found version 1 property `package_manager`; use `package-ecosystem` in version 2)"
+        ),
+        "{e}"
+    );
+
+    // Suggestions are also added by `parse_all_errors`.
+    let (_, errors) = v2::Dependabot::parse_all_errors(
+        "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daly
+",
+    );
+    assert!(
+        errors[0].to_string().contains("(did you mean `daily`?) at line 6 column 17"),
+        "{}",
+        errors[0]
+    );
+    #[cfg(not(feature = "other-variants"))]
+    {
+        let (_, errors) = v2::Dependabot::parse_all_errors(
+            "version: 2
+updates:
+  - package-ecosystem: carg
+    directory: /
+    schedule:
+      interval: daily
+",
+        );
+        assert!(
+            errors[0].to_string().contains("(did you mean `cargo`?) at line 3 column 24"),
+            "{}",
+            errors[0]
+        );
+    }
+}
+
+#[test]
+fn test_error_location() {
+    let s = "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: hourly
+";
+    let e = s.parse::<v2::Dependabot>().unwrap_err();
+    let location = e.location().unwrap();
+    assert_eq!((location.line(), location.column()), (6, 17));
+    assert_eq!(&s[location.index()..], "hourly\n");
+    assert_eq!(location.path(), Some("updates[0].schedule.interval"));
+
+    // Syntax error
+    let e = "version: 2\nupdates: [\n".parse::<v2::Dependabot>().unwrap_err();
+    let location = e.location().unwrap();
+    assert_eq!(location.line(), 3);
+    assert_eq!(location.path(), None);
+
+    // Error about the top-level value
+    let e = "updates: []\n".parse::<v2::Dependabot>().unwrap_err();
+    let location = e.location().unwrap();
+    assert_eq!((location.line(), location.column(), location.path()), (1, 1, None));
+
+    // Error not from the YAML input
+    let e = "25:00".parse::<v2::ScheduleTime>().unwrap_err();
+    assert_eq!(e.location(), None);
+
+    // Keys containing spaces and ": "
+    let e = "version: 2
+registries:
+  my registry:
+    type: git
+    replaces-base: maybe
+updates: []
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert_eq!(e.location().unwrap().path(), Some("registries.my registry.replaces-base"));
+    let e = "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+    groups:
+      'dev: deps':
+        dependency-type: foo
+"
+    .parse::<v2::Dependabot>()
+    .unwrap_err();
+    assert!(e.is_invalid_enum_value(), "{e}");
+    assert_eq!(e.location().unwrap().path(), Some("updates[0].groups.dev: deps.dependency-type"));
+}
+
+#[test]
+fn test_error_kind() {
+    let update = |s: &str| {
+        format!(
+            "version: 2
+updates:
+  - package-ecosystem: cargo
+    directory: /
+    schedule:
+      interval: daily
+{s}"
+        )
+        .parse::<v2::Dependabot>()
+        .unwrap_err()
+    };
+    let kinds = |e: &dependabot_config::Error| {
+        [
+            e.is_syntax(),
+            e.is_unknown_field(),
+            e.is_invalid_enum_value(),
+            e.is_missing_field(),
+            e.is_version_mismatch(),
+            e.is_invalid_separator(),
+            e.is_semantic(),
+        ]
+        .iter()
+        .filter(|&&b| b)
+        .count()
+    };
+
+    let e = "version: 2\nupdates: [\n".parse::<v2::Dependabot>().unwrap_err();
+    assert!(e.is_syntax(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    let e = "version: 2\nupdates:\n  - package-ecosystem: cargo\n    directory: /\n    schedule:\n      interval: foo\n"
+        .parse::<v2::Dependabot>()
+        .unwrap_err();
+    assert!(e.is_invalid_enum_value(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    #[cfg(not(feature = "other-variants"))]
+    {
+        let e = "version: 2\nupdates:\n  - package-ecosystem: foo\n"
+            .parse::<v2::Dependabot>()
+            .unwrap_err();
+        assert!(e.is_invalid_enum_value(), "{e}");
+        assert_eq!(kinds(&e), 1);
+    }
+    let e = "version: 2\n".parse::<v2::Dependabot>().unwrap_err();
+    assert!(e.is_missing_field(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    let e = "version: 1\nupdates: []\n".parse::<v2::Dependabot>().unwrap_err();
+    assert!(e.is_version_mismatch(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    let e = "version: 2\nupdate_configs: []\n".parse::<v1::Dependabot>().unwrap_err();
+    assert!(e.is_version_mismatch(), "{e}");
+    let e = update("    pull-request-branch-name:\n      separator: '+'\n");
+    assert!(e.is_invalid_separator(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    let e = update("    cooldown:\n      default-days: 100\n");
+    assert!(e.is_semantic(), "{e}");
+    assert_eq!(kinds(&e), 1);
+    let e = "0 0 * *".parse::<v2::CronExpression>().unwrap_err();
+    assert!(e.is_semantic(), "{e}");
+    let e = serde_yaml::from_str::<v2::TypedRegistry>("type: git\n").unwrap_err();
+    assert!(e.to_string().starts_with("registry of type 'git'"), "{e}");
+    let e = "type: git\n".parse::<v2::TypedRegistry>().unwrap_err();
+    assert!(e.is_semantic(), "{e}");
+    let e = update("    milestone: foo\n");
+    assert_eq!(kinds(&e), 0, "{e}");
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct TestRegistriesV2 {
     registries: v2::Registries,
@@ -17,6 +17,8 @@ fn workspace_root() -> &'static Path {
 fn main() {
     gen_from_str();
     gen_display();
+    gen_extra();
+    gen_tz();
     gen_assert_impl();
     gen_track_size();
 }
@@ -30,6 +32,23 @@ fn gen_from_str() {
     };
 
     let files = &["src/lib.rs", "src/v1.rs", "src/v2.rs"];
+    // Types that implement FromStr manually, that cannot be parsed on their own,
+    // or that are only produced by this crate.
+    let exclude = &[
+        "Candidate",
+        "CronExpression",
+        "Decision",
+        "DecisionReason",
+        "Diagnostic",
+        "Document",
+        "LossyConversionError",
+        "LossyItem",
+        "ScheduleTime",
+        "Timezone",
+        "UpdateMut",
+        "Version",
+        "VersionRequirement",
+    ];
 
     for &f in files {
         let s = fs::read_to_string(workspace_root.join(f)).unwrap();
@@ -45,13 +64,14 @@ fn gen_from_str() {
         test_helper::codegen::visit_items(module, ast, |item, module| match item {
             syn::Item::Struct(syn::ItemStruct { vis, ident, .. })
             | syn::Item::Enum(syn::ItemEnum { vis, ident, .. })
-                if matches!(vis, syn::Visibility::Public(..)) =>
+                if matches!(vis, syn::Visibility::Public(..))
+                    && !exclude.contains(&&*ident.to_string()) =>
             {
                 tokens.extend(quote! {
                     impl FromStr for crate:: #(#module::)* #ident {
                         type Err = Error;
                         fn from_str(s: &str) -> Result<Self, Self::Err> {
-                            serde_yaml::from_str(s).map_err(Error::new)
+                            crate::yaml::from_str(s)
                         }
                     }
                 });
@@ -89,6 +109,38 @@ fn serde_attr(attrs: &[syn::Attribute], name: &str) -> Option<String> {
     None
 }
 
+fn has_serde_flag(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok()
+        })
+        .flatten()
+        .any(|meta| matches!(meta, syn::Meta::Path(path) if path.is_ident(name)))
+}
+
+/// Returns the serde attributes in `attrs` without the items that only affect
+/// serialization.
+fn deserialize_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            let metas: Punctuated<Meta, Token![,]> = attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()?
+                .into_iter()
+                .filter(|meta| {
+                    !["skip_serializing", "skip_serializing_if", "serialize_with"]
+                        .iter()
+                        .any(|name| meta.path().is_ident(name))
+                })
+                .collect();
+            (!metas.is_empty()).then(|| parse_quote!(#[serde(#metas)]))
+        })
+        .collect()
+}
 fn change_case(case: Option<&str>, value: String) -> String {
     match case {
         None => value,
@@ -119,10 +171,22 @@ fn gen_display() {
         test_helper::codegen::visit_items(module, ast, |item, module| match item {
             syn::Item::Enum(syn::ItemEnum { attrs, vis, ident, variants, .. })
                 if matches!(vis, syn::Visibility::Public(..))
-                    && variants.iter().all(|v| matches!(v.fields, syn::Fields::Unit)) =>
+                    && variants.iter().all(|v| {
+                        matches!(v.fields, syn::Fields::Unit)
+                            // Untagged newtype variants are (de)serialized verbatim.
+                            || matches!(&v.fields, syn::Fields::Unnamed(f) if f.unnamed.len() == 1)
+                                && has_serde_flag(&v.attrs, "untagged")
+                    }) =>
             {
                 let case = serde_attr(attrs, "rename_all");
-                let arms = variants.iter().map(|syn::Variant { attrs, ident, .. }| {
+                let arms = variants.iter().map(|syn::Variant { attrs, ident, fields, .. }| {
+                    if !matches!(fields, syn::Fields::Unit) {
+                        let cfg = attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+                        return quote! {
+                            #(#cfg)*
+                            Self::#ident(s) => fmt::Display::fmt(s, f),
+                        };
+                    }
                     let rename = serde_attr(attrs, "rename");
                     let s = if let Some(rename) = rename {
                         rename
@@ -156,6 +220,148 @@ fn gen_display() {
     );
 }
 
+fn gen_extra() {
+    let workspace_root = workspace_root();
+
+    let mut tokens = quote! {
+        use alloc::{string::String, vec::Vec};
+        use indexmap::IndexMap;
+        use serde::de::{Deserialize, Deserializer};
+        use serde_derive::Deserialize;
+        #[allow(clippy::wildcard_imports)]
+        use crate::{v2::*, Value};
+    };
+
+    let f = "src/v2.rs";
+    let s = fs::read_to_string(workspace_root.join(f)).unwrap();
+    let ast = syn::parse_file(&s).unwrap();
+
+    let module = vec![format_ident!("v2").into()];
+
+    test_helper::codegen::visit_items(module, ast, |item, module| match item {
+        syn::Item::Struct(syn::ItemStruct {
+            attrs,
+            vis,
+            ident,
+            fields: syn::Fields::Named(fields),
+            ..
+        }) if matches!(vis, syn::Visibility::Public(..))
+            && fields.named.iter().any(|f| {
+                f.ident.as_ref().unwrap() == "extra" && has_serde_flag(&f.attrs, "flatten")
+            }) =>
+        {
+            let known: Vec<_> =
+                fields.named.iter().filter(|f| !has_serde_flag(&f.attrs, "flatten")).collect();
+            // The names of the properties as serde (de)serializes them.
+            let case = serde_attr(attrs, "rename_all");
+            let names = known.iter().map(|f| {
+                serde_attr(&f.attrs, "rename").unwrap_or_else(|| {
+                    change_case(case.as_deref(), f.ident.as_ref().unwrap().to_string())
+                })
+            });
+            // The struct without `extra`, whose derived `Deserialize` is used
+            // for the known properties.
+            let fields_ident = format_ident!("{ident}Fields");
+            let container_attrs = deserialize_attrs(attrs);
+            let field_defs = known.iter().map(|f| {
+                let attrs = deserialize_attrs(&f.attrs);
+                let (ident, ty) = (&f.ident, &f.ty);
+                quote! { #(#attrs)* #ident: #ty }
+            });
+            let field_idents = known.iter().map(|f| &f.ident);
+            let name = ident.to_string();
+            tokens.extend(quote! {
+                impl Extra for crate:: #(#module::)* #ident {
+                    const FIELDS: &'static [&'static str] = &[#(#names),*];
+                    fn extra(&self) -> &IndexMap<String, Value> {
+                        &self.extra
+                    }
+                }
+                #[derive(Deserialize)]
+                #(#container_attrs)*
+                struct #fields_ident {
+                    #(#field_defs,)*
+                }
+                impl<'de> Deserialize<'de> for crate:: #(#module::)* #ident {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        let (fields, extra): (#fields_ident, _) =
+                            deserialize_extra(deserializer, #name, Self::FIELDS)?;
+                        Ok(Self { #(#field_idents: fields.#field_idents,)* extra })
+                    }
+                }
+            });
+        }
+        _ => {}
+    });
+
+    file::write(
+        function_name!(),
+        bin_name!(),
+        workspace_root,
+        workspace_root.join("src/gen/extra.rs"),
+        tokens,
+    );
+}
+
+/// The version of the tz database that src/gen/tz.rs is generated from.
+const TZDATA_VERSION: &str = "2025b";
+
+fn gen_tz() {
+    let workspace_root = workspace_root();
+    let zoneinfo = Path::new("/usr/share/zoneinfo");
+
+    // Use the tz database installed on the system, but require the pinned
+    // version so that the output doesn't depend on the host.
+    let tzdata = fs::read_to_string(zoneinfo.join("tzdata.zi")).unwrap();
+    let version = tzdata.lines().find_map(|line| line.strip_prefix("# version ")).unwrap();
+    assert_eq!(
+        version,
+        TZDATA_VERSION,
+        "install tzdata {TZDATA_VERSION} or update TZDATA_VERSION in {}",
+        file!()
+    );
+    // GitHub only accepts identifiers in the Area/Location form, so legacy
+    // zones such as `EST` and `EST5EDT` and links such as `GMT+0` and
+    // `US/Eastern` are excluded. zone.tab and zone1970.tab list them
+    // regardless of the options the database was built with (e.g., backzone).
+    let mut names = vec![];
+    for tab in ["zone.tab", "zone1970.tab"] {
+        let tab = fs::read_to_string(zoneinfo.join(tab)).unwrap();
+        for line in tab.lines().filter(|line| !line.starts_with('#')) {
+            // COUNTRY-CODE COORDINATES TZ [COMMENTS]
+            names.push(line.split('\t').nth(2).unwrap().to_owned());
+        }
+    }
+    // The default of `schedule.timezone`.
+    names.extend(["Etc/UTC".to_owned(), "UTC".to_owned()]);
+    names.sort_unstable();
+    names.dedup();
+    let rules = names.iter().map(|name| {
+        // The last line of a TZif file (version 2 or later) is a POSIX TZ string that
+        // describes the rule for times after the last transition stored in the file.
+        let tzif = fs::read(zoneinfo.join(name)).unwrap();
+        let footer = tzif.strip_suffix(b"\n").unwrap();
+        let start = footer.iter().rposition(|&b| b == b'\n').unwrap() + 1;
+        std::str::from_utf8(&footer[start..]).unwrap().to_owned()
+    });
+
+    let tokens = quote! {
+        /// (IANA time zone identifier, POSIX TZ string) pairs, sorted by identifier.
+        pub(crate) static TIME_ZONES: &[(&str, &str)] = &[#((#names, #rules),)*];
+    };
+
+    file::write(
+        function_name!(),
+        bin_name!(),
+        workspace_root,
+        workspace_root.join("src/gen/tz.rs"),
+        tokens,
+    );
+}
+
 fn gen_assert_impl() {
     let workspace_root = workspace_root();
     let (path, out) = test_helper::codegen::gen_assert_impl(
@@ -165,7 +371,7 @@ fn gen_assert_impl() {
             not_send: &[],
             not_sync: &[],
             not_unpin: &[],
-            not_unwind_safe: &["error::Error"],
+            not_unwind_safe: &["error::Error", "v2::UpdateMut"],
             not_ref_unwind_safe: &["error::Error"],
         },
     );